@@ -1,3 +1,12 @@
+## Language metadata, shown in the language selection, sent to the weather provider
+## and used to format numbers and dates
+language-name = Deutsch
+language-flag = 🇩🇪
+language-provider-code = de
+language-locale = de_DE
+
+## Messages
+
 hub-message =
     👋 <b>Hallo!</b>
 
@@ -12,6 +21,11 @@ start =
 
     Um die Wettervorhersage zu erhalten, gib den Namen deiner Stadt ein
 
+start-group =
+    👋🏻 Hallo!
+
+    Um die Wettervorhersage in dieser Gruppe zu erhalten, sollte ein Administrator auf diese Nachricht mit dem Namen der Stadt antworten
+
 
 language-no-change = Die ausgewählte Sprache ist bereits aktiv.
 language-success = Sprache erfolgreich aktualisiert!
@@ -20,19 +34,24 @@ save-city-success = ✅ Die Stadt wurde erfolgreich gespeichert!
 validation-city = ⚠️ Bitte gib eine gültige Stadt ein. Versuch es erneut.
 saving-error = ❌ Fehler beim Speichern. Bitte versuche es erneut.
 error = ❌ Etwas ist schief gelaufen. Bitte versuche es erneut.
+group-admin-only = Nur Gruppenadministratoren können die Einstellungen der Gruppe ändern.
 
+now = Jetzt
 today = Heute
 tomorrow = Morgen
+sun-button = 🌅 Sonne
+air-quality-button = 🌬️ Luftqualität
+chart-button = 📈 Diagramm
 settings = Einstellungen
 
 weather =
-    🌤️ <b>Wetter in { $city } für { $day }</b>
+    🌤️ <b>Wetter in { $city } für { $day }</b>{ $date }
 
     { $emoji } { $description }
 
-    🌡️ <b>Temperatur</b>: { $temp }{ $temp_unit } (gefühlt { $feels_like }{ $temp_unit })
+    🌡️ <b>Temperatur</b>: { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit } (gefühlt { NUMBER($feels_like, maximumFractionDigits: 0) }{ $temp_unit })
     💧 <b>Luftfeuchtigkeit</b>: { $humidity }%
-    💨 <b>Wind</b>: { $wind_speed }
+    💨 <b>Wind</b>: { $wind_speed }{ $uv }{ $sun }
 
     <i>Einen schönen Tag!</i> ☀️
 
@@ -41,16 +60,86 @@ weather-wind-speed-kmh = km/h
 
 weather-wind-speed-unknown = nicht verfügbar
 
+weather-date-line = 📅 { DATETIME($date, weekday: "long", day: "numeric", month: "long") }
+weather-sun-line = 🌅 <b>Sonne</b>: { $sunrise } – { $sunset }
+weather-uv-line = ☀️ <b>UV-Index</b>: { $uv } { $emoji } { $risk }. { $advice }
+
+sun =
+    🌅 <b>Sonne in { $city }</b>
+
+    🌄 <b>Sonnenaufgang</b>: { $sunrise }
+    🌇 <b>Sonnenuntergang</b>: { $sunset }
+    ⏳ <b>Tageslänge</b>: { $hours } Std. { $minutes } Min.
+
+    <i>Zeiten in der Ortszeit der Stadt</i>
+
+uv-low = niedrig
+uv-moderate = mäßig
+uv-high = hoch
+uv-very-high = sehr hoch
+uv-extreme = extrem
+
+uv-advice-low = Kein Schutz erforderlich.
+uv-advice-moderate = Trage eine Sonnenbrille und nutze Sonnencreme mit LSF 30+.
+uv-advice-high = Nutze Sonnencreme mit LSF 30+, trage einen Hut und suche mittags Schatten.
+uv-advice-very-high = Nutze Sonnencreme mit LSF 50+, trage sie alle 2 Stunden neu auf und meide die Sonne von 11:00 bis 16:00.
+uv-advice-extreme = Nutze Sonnencreme mit LSF 50+ und bleib mittags nach Möglichkeit drinnen.
+
+uv-alert = ☀️ <b>{ $city }</b>: Der UV-Index liegt heute bei { $uv } ({ $risk }). { $advice }
+uv-alerts-enabled = 🔔 Warnungen bei hohem UV-Index sind aktiviert.
+uv-alerts-disabled = 🔕 Warnungen bei hohem UV-Index sind deaktiviert.
+
+air-quality =
+    🌬️ <b>Luftqualität in { $city }</b>
+
+    { $emoji } <b>{ $category }</b> (AQI { $aqi } von 5)
+
+    <b>PM2.5</b>: { NUMBER($pm2_5, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>PM10</b>: { NUMBER($pm10, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>O₃</b>: { NUMBER($o3, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>NO₂</b>: { NUMBER($no2, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+
+    💡 { $advice }
+
+aqi-good = Gut
+aqi-fair = Ausreichend
+aqi-moderate = Mäßig
+aqi-poor = Schlecht
+aqi-very-poor = Sehr schlecht
+
+aqi-advice-good = Die Luft ist sauber. Genieße die Zeit draußen!
+aqi-advice-fair = Die Luftqualität ist akzeptabel. Besonders empfindliche Personen sollten längere Anstrengung im Freien einschränken.
+aqi-advice-moderate = Menschen mit Asthma, Herz- oder Lungenerkrankungen sollten längere Anstrengung im Freien reduzieren und ihr Inhalationsgerät bereithalten.
+aqi-advice-poor = Schränke Aktivitäten im Freien ein. Empfindliche Gruppen sollten drinnen bleiben und die Fenster geschlossen halten.
+aqi-advice-very-poor = Vermeide Aktivitäten im Freien. Halte die Fenster geschlossen und nutze nach Möglichkeit einen Luftreiniger.
+
+chart-caption = 📈 Temperatur und Niederschlagswahrscheinlichkeit für die nächsten 48 Stunden in <b>{ $city }</b>
+
+forecast-summary = { $emoji } { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit }, { $description }
+inline-title = { $day } — { $city }
+
 service-error = Fehler des Dienstes, wenden Sie sich bitte an die Entwickler
 user-not-found = Sie wurden nicht gefunden. Versuchen Sie, den Bot mit dem Befehl /start neu zu starten.
 api-fetch-error = Es konnten keine Wetterdaten abgerufen werden. Versuchen Sie es später erneut.
+weather-city-not-found = 🏙️ Der Wetterdienst kennt diese Stadt nicht. Prüfe den Namen oder wähle eine andere Stadt.
+weather-invalid-api-key = 🔑 Der Wetterdienst hat den API-Schlüssel des Bots abgelehnt. Bitte wende dich an die Entwickler.
+weather-quota-exceeded = ⏳ Das Anfragelimit des Wetterdienstes ist erreicht. Bitte versuche es später erneut.
+weather-timeout = ⌛ Der Wetterdienst hat nicht rechtzeitig geantwortet. Bitte versuche es erneut.
+weather-invalid-response = ⚠️ Der Wetterdienst hat eine unerwartete Antwort geliefert. Bitte versuche es später erneut.
+telegram-error = ❌ Die Nachricht konnte nicht aktualisiert werden. Bitte versuche es erneut.
+stale-data = <i>⚠️ Wetterdienst nicht erreichbar, Daten von { $time }</i>
 no-forecast-data = Die Wettervorhersage für den ausgewählten Zeitraum ist nicht verfügbar.
 missing-message = Fehler bei der Bearbeitung der Anfrage.
+throttled = ⏳ Zu viele Anfragen. Bitte warte { $seconds } s und versuche es erneut.
 
 back = ⬅️ Zurück
 
 select-language = Sprache auswählen
 select-units = Einheitseinstellungen
+change-city-button = 🏙️ Stadt ändern
+uv-alerts-button = ☀️ UV-Warnungen
+change-city = 🏙️ Gib den Namen der neuen Stadt ein
+change-city-group = 🏙️ Ein Administrator sollte auf diese Nachricht mit dem Namen der neuen Stadt antworten
 
 
 settings-hub = 
@@ -89,3 +178,74 @@ settings-units-speed-hub =
 
 speed-unit-no-change = Die ausgewählte Geschwindigkeitseinheit ist bereits aktiv.
 speed-unit-success = Geschwindigkeitseinheit wurde erfolgreich aktualisiert!
+
+
+condition-thunderstorm = Gewitter
+condition-drizzle = Nieselregen
+condition-rain = Regen
+condition-freezing-rain = gefrierender Regen
+condition-shower-rain = Regenschauer
+condition-snow = Schnee
+condition-sleet = Schneeregen
+condition-shower-snow = Schneeschauer
+condition-mist = Dunst
+condition-smoke = Rauch
+condition-haze = Trübung
+condition-dust = Staub
+condition-fog = Nebel
+condition-ash = Vulkanasche
+condition-squall = Böen
+condition-tornado = Tornado
+condition-clear = klarer Himmel
+condition-few-clouds = leicht bewölkt
+condition-scattered-clouds = aufgelockert bewölkt
+condition-broken-clouds = überwiegend bewölkt
+condition-overcast = bedeckt
+condition-unknown = keine Daten
+
+
+admin-stats =
+    📊 <b>Statistik</b>
+
+    Nutzer: { $users }
+    Gruppen: { $groups }
+    Aktiv in den letzten { $days } Tagen: { $active }
+    Bot blockiert: { $inactive }
+
+    <b>Sprachen</b>
+    { $languages }
+
+    <b>Temperatureinheiten</b>
+    { $temperature }
+
+    <b>Geschwindigkeitseinheiten</b>
+    { $speed }
+
+admin-user =
+    👤 <b>Eintrag { $id }</b>
+
+    Stadt: { $city }
+    Sprache: { $language }
+    Temperatureinheit: { $temperature }
+    Geschwindigkeitseinheit: { $speed }
+    Erstellt: { $created }
+    Aktualisiert: { $updated }
+    Zuletzt aktiv: { $active }
+
+admin-user-not-found = Kein Eintrag mit der ID { $id }.
+admin-user-usage = Verwendung: /user &lt;id&gt;
+admin-broadcast-usage = Verwendung: /broadcast <Text>
+admin-broadcast-progress = 📣 Sende… { $sent } von { $total } gesendet, { $blocked } blockiert, { $failed } fehlgeschlagen.
+admin-broadcast-done = ✅ Rundnachricht abgeschlossen: { $sent } von { $total } gesendet, { $blocked } blockiert, { $failed } fehlgeschlagen.
+
+privacy-private-only = 🔒 Sende diesen Befehl in einem privaten Chat mit dem Bot.
+privacy-no-data = Der Bot speichert keine Daten über dich.
+delete-confirm =
+    ⚠️ Dadurch wird alles, was der Bot über dich speichert, endgültig gelöscht: deine Stadt, Sprach- und Einheiteneinstellungen sowie Aktivitätszeitpunkte.
+
+    Deine Daten löschen?
+delete-confirm-button = 🗑 Meine Daten löschen
+delete-cancel-button = Abbrechen
+delete-done = ✅ Deine Daten wurden gelöscht. Sende /start, um den Bot wieder zu nutzen.
+delete-cancelled = Löschen abgebrochen, deine Daten bleiben erhalten.
+export-caption = 📦 Alles, was der Bot über dich speichert.
@@ -1,3 +1,12 @@
+## Language metadata, shown in the language selection, sent to the weather provider
+## and used to format numbers and dates
+language-name = English
+language-flag = 🇺🇸
+language-provider-code = en
+language-locale = en_US
+
+## Messages
+
 hub-message =
     👋 <b>Hello!</b>
 
@@ -12,6 +21,11 @@ start =
 
     To get the weather forecast, enter the name of your city
 
+start-group =
+    👋🏻 Hello!
+
+    To get the weather forecast in this group, an administrator should reply to this message with the name of the city
+
 
 language-no-change = The selected language is already active.
 language-success = Language updated successfully!
@@ -20,19 +34,24 @@ save-city-success = ✅ The city has been successfully saved!
 validation-city = ⚠️ Please enter a valid city. Try again.
 saving-error = ❌ Error saving. Please try again.
 error = ❌ Something went wrong. Please try again.
+group-admin-only = Only group administrators can change the group's settings.
 
+now = Now
 today = Today
 tomorrow = Tomorrow
+sun-button = 🌅 Sun
+air-quality-button = 🌬️ Air quality
+chart-button = 📈 Chart
 settings = Settings
 
 weather =
-    🌤️ <b>Weather in { $city } for { $day }</b>
+    🌤️ <b>Weather in { $city } for { $day }</b>{ $date }
 
     { $emoji } { $description }
 
-    🌡️ <b>Temperature</b>: { $temp }{ $temp_unit } (feels like { $feels_like }{ $temp_unit })
+    🌡️ <b>Temperature</b>: { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit } (feels like { NUMBER($feels_like, maximumFractionDigits: 0) }{ $temp_unit })
     💧 <b>Humidity</b>: { $humidity }%
-    💨 <b>Wind</b>: { $wind_speed }
+    💨 <b>Wind</b>: { $wind_speed }{ $uv }{ $sun }
 
     <i>Have a great day!</i> ☀️
 
@@ -41,16 +60,86 @@ weather-wind-speed-kmh = km/h
 
 weather-wind-speed-unknown = unavailable
 
+weather-date-line = 📅 { DATETIME($date, weekday: "long", day: "numeric", month: "long") }
+weather-sun-line = 🌅 <b>Sun</b>: { $sunrise } – { $sunset }
+weather-uv-line = ☀️ <b>UV index</b>: { $uv } { $emoji } { $risk }. { $advice }
+
+sun =
+    🌅 <b>Sun in { $city }</b>
+
+    🌄 <b>Sunrise</b>: { $sunrise }
+    🌇 <b>Sunset</b>: { $sunset }
+    ⏳ <b>Day length</b>: { $hours } h { $minutes } min
+
+    <i>Times are shown in the city's local time</i>
+
+uv-low = low
+uv-moderate = moderate
+uv-high = high
+uv-very-high = very high
+uv-extreme = extreme
+
+uv-advice-low = No protection needed.
+uv-advice-moderate = Wear sunglasses and use SPF 30+ sunscreen.
+uv-advice-high = Use SPF 30+ sunscreen, wear a hat and seek shade around midday.
+uv-advice-very-high = Use SPF 50+ sunscreen, reapply every 2 hours and avoid the sun from 11:00 to 16:00.
+uv-advice-extreme = Use SPF 50+ sunscreen and stay indoors around midday if you can.
+
+uv-alert = ☀️ <b>{ $city }</b>: UV index today is { $uv } ({ $risk }). { $advice }
+uv-alerts-enabled = 🔔 High UV index alerts are on.
+uv-alerts-disabled = 🔕 High UV index alerts are off.
+
+air-quality =
+    🌬️ <b>Air quality in { $city }</b>
+
+    { $emoji } <b>{ $category }</b> (AQI { $aqi } of 5)
+
+    <b>PM2.5</b>: { NUMBER($pm2_5, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>PM10</b>: { NUMBER($pm10, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>O₃</b>: { NUMBER($o3, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>NO₂</b>: { NUMBER($no2, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+
+    💡 { $advice }
+
+aqi-good = Good
+aqi-fair = Fair
+aqi-moderate = Moderate
+aqi-poor = Poor
+aqi-very-poor = Very poor
+
+aqi-advice-good = Air quality is great. Enjoy outdoor activities!
+aqi-advice-fair = Air quality is acceptable. Unusually sensitive people should consider limiting prolonged outdoor exertion.
+aqi-advice-moderate = People with asthma, heart or lung conditions should reduce prolonged outdoor exertion and keep their inhaler at hand.
+aqi-advice-poor = Limit outdoor activity. Sensitive groups should stay indoors and keep windows closed.
+aqi-advice-very-poor = Avoid outdoor activity. Keep windows closed and use an air purifier if you have one.
+
+chart-caption = 📈 Temperature and precipitation probability for the next 48 hours in <b>{ $city }</b>
+
+forecast-summary = { $emoji } { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit }, { $description }
+inline-title = { $day } — { $city }
+
 service-error = Service error, please contact the developers
 user-not-found = You were not found. Try restarting the bot using the /start command.
 api-fetch-error = Unable to retrieve weather data. Please try again later.
+weather-city-not-found = 🏙️ The weather service doesn't know this city. Check the name or choose another city.
+weather-invalid-api-key = 🔑 The weather service rejected the bot's API key. Please contact the developers.
+weather-quota-exceeded = ⏳ The weather service request limit has been reached. Please try again later.
+weather-timeout = ⌛ The weather service took too long to respond. Please try again.
+weather-invalid-response = ⚠️ The weather service returned an unexpected response. Please try again later.
+telegram-error = ❌ Could not update the message. Please try again.
+stale-data = <i>⚠️ Weather service is unavailable, showing data as of { $time }</i>
 no-forecast-data = Weather forecast not available for the selected period
 missing-message = Error processing request.
+throttled = ⏳ Too many requests. Please wait { $seconds } s and try again.
 
 back = ⬅️ Back
 
 select-language = Select language
 select-units = Units settings
+change-city-button = 🏙️ Change city
+uv-alerts-button = ☀️ UV alerts
+change-city = 🏙️ Enter the name of the new city
+change-city-group = 🏙️ An administrator should reply to this message with the name of the new city
 
 
 settings-hub = 
@@ -88,3 +177,74 @@ settings-units-speed-hub =
 
 speed-unit-no-change = The selected speed unit is already active.
 speed-unit-success = Speed unit updated successfully!
+
+
+condition-thunderstorm = thunderstorm
+condition-drizzle = drizzle
+condition-rain = rain
+condition-freezing-rain = freezing rain
+condition-shower-rain = rain showers
+condition-snow = snow
+condition-sleet = sleet
+condition-shower-snow = snow showers
+condition-mist = mist
+condition-smoke = smoke
+condition-haze = haze
+condition-dust = dust
+condition-fog = fog
+condition-ash = volcanic ash
+condition-squall = squalls
+condition-tornado = tornado
+condition-clear = clear sky
+condition-few-clouds = few clouds
+condition-scattered-clouds = scattered clouds
+condition-broken-clouds = broken clouds
+condition-overcast = overcast
+condition-unknown = no data
+
+
+admin-stats =
+    📊 <b>Statistics</b>
+
+    Users: { $users }
+    Groups: { $groups }
+    Active in the last { $days } days: { $active }
+    Blocked the bot: { $inactive }
+
+    <b>Languages</b>
+    { $languages }
+
+    <b>Temperature units</b>
+    { $temperature }
+
+    <b>Speed units</b>
+    { $speed }
+
+admin-user =
+    👤 <b>Record { $id }</b>
+
+    City: { $city }
+    Language: { $language }
+    Temperature unit: { $temperature }
+    Speed unit: { $speed }
+    Created: { $created }
+    Updated: { $updated }
+    Last active: { $active }
+
+admin-user-not-found = No record with ID { $id }.
+admin-user-usage = Usage: /user &lt;id&gt;
+admin-broadcast-usage = Usage: /broadcast <text>
+admin-broadcast-progress = 📣 Broadcasting… { $sent } of { $total } sent, { $blocked } blocked, { $failed } failed.
+admin-broadcast-done = ✅ Broadcast finished: { $sent } of { $total } sent, { $blocked } blocked, { $failed } failed.
+
+privacy-private-only = 🔒 Send this command in a private chat with the bot.
+privacy-no-data = The bot stores no data about you.
+delete-confirm =
+    ⚠️ This permanently deletes everything the bot stores about you: your city, language and unit settings and activity timestamps.
+
+    Delete your data?
+delete-confirm-button = 🗑 Delete my data
+delete-cancel-button = Cancel
+delete-done = ✅ Your data has been deleted. Send /start to use the bot again.
+delete-cancelled = Deletion cancelled, your data is kept.
+export-caption = 📦 Everything the bot stores about you.
@@ -0,0 +1,259 @@
+## Language metadata, shown in the language selection, sent to the weather provider
+## and used to format numbers and dates
+language-name = Español
+language-flag = 🇪🇸
+language-provider-code = es
+language-locale = es_ES
+
+## Messages
+
+hub-message =
+    👋 <b>¡Hola!</b>
+
+    <b>Tu ciudad</b>: { $city }
+
+    🔹 Elige una acción abajo ⬇️
+
+
+
+start =
+    👋🏻 ¡Hola!
+
+    Para recibir el pronóstico del tiempo, escribe el nombre de tu ciudad
+
+start-group =
+    👋🏻 ¡Hola!
+
+    Para recibir el pronóstico en este grupo, un administrador debe responder a este mensaje con el nombre de la ciudad
+
+
+language-no-change = El idioma seleccionado ya está activo.
+language-success = ¡Idioma actualizado!
+
+save-city-success = ✅ ¡La ciudad se ha guardado correctamente!
+validation-city = ⚠️ Escribe una ciudad válida. Inténtalo de nuevo.
+saving-error = ❌ Error al guardar. Inténtalo de nuevo.
+error = ❌ Algo salió mal. Inténtalo de nuevo.
+group-admin-only = Solo los administradores del grupo pueden cambiar su configuración.
+
+now = Ahora
+today = Hoy
+tomorrow = Mañana
+sun-button = 🌅 Sol
+air-quality-button = 🌬️ Calidad del aire
+chart-button = 📈 Gráfico
+settings = Ajustes
+
+weather =
+    🌤️ <b>El tiempo en { $city } para { $day }</b>{ $date }
+
+    { $emoji } { $description }
+
+    🌡️ <b>Temperatura</b>: { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit } (sensación de { NUMBER($feels_like, maximumFractionDigits: 0) }{ $temp_unit })
+    💧 <b>Humedad</b>: { $humidity } %
+    💨 <b>Viento</b>: { $wind_speed }{ $uv }{ $sun }
+
+    <i>¡Que tengas un buen día!</i> ☀️
+
+
+weather-wind-speed-kmh = km/h
+
+weather-wind-speed-unknown = no disponible
+
+weather-date-line = 📅 { DATETIME($date, weekday: "long", day: "numeric", month: "long") }
+weather-sun-line = 🌅 <b>Sol</b>: { $sunrise } – { $sunset }
+weather-uv-line = ☀️ <b>Índice UV</b>: { $uv } { $emoji } { $risk }. { $advice }
+
+sun =
+    🌅 <b>El sol en { $city }</b>
+
+    🌄 <b>Amanecer</b>: { $sunrise }
+    🌇 <b>Atardecer</b>: { $sunset }
+    ⏳ <b>Duración del día</b>: { $hours } { $hours ->
+        [one] hora
+       *[other] horas
+    } { $minutes } min
+
+    <i>Las horas se muestran en la hora local de la ciudad</i>
+
+uv-low = bajo
+uv-moderate = moderado
+uv-high = alto
+uv-very-high = muy alto
+uv-extreme = extremo
+
+uv-advice-low = No se necesita protección.
+uv-advice-moderate = Usa gafas de sol y protector solar SPF 30+.
+uv-advice-high = Usa protector SPF 30+, sombrero y busca la sombra al mediodía.
+uv-advice-very-high = Usa protector SPF 50+, vuelve a aplicarlo cada 2 horas y evita el sol de 11:00 a 16:00.
+uv-advice-extreme = Usa protector SPF 50+ y, si puedes, quédate en interiores al mediodía.
+
+uv-alert = ☀️ <b>{ $city }</b>: El índice UV de hoy es { $uv } ({ $risk }). { $advice }
+uv-alerts-enabled = 🔔 Las alertas de índice UV alto están activadas.
+uv-alerts-disabled = 🔕 Las alertas de índice UV alto están desactivadas.
+
+air-quality =
+    🌬️ <b>Calidad del aire en { $city }</b>
+
+    { $emoji } <b>{ $category }</b> (AQI { $aqi } de 5)
+
+    <b>PM2.5</b>: { NUMBER($pm2_5, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>PM10</b>: { NUMBER($pm10, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>O₃</b>: { NUMBER($o3, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>NO₂</b>: { NUMBER($no2, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+
+    💡 { $advice }
+
+aqi-good = Buena
+aqi-fair = Aceptable
+aqi-moderate = Moderada
+aqi-poor = Mala
+aqi-very-poor = Muy mala
+
+aqi-advice-good = La calidad del aire es excelente. ¡Disfruta de las actividades al aire libre!
+aqi-advice-fair = La calidad del aire es aceptable. Las personas especialmente sensibles deberían limitar el esfuerzo prolongado al aire libre.
+aqi-advice-moderate = Las personas con asma o enfermedades cardíacas o pulmonares deberían reducir el esfuerzo prolongado al aire libre y tener el inhalador a mano.
+aqi-advice-poor = Limita la actividad al aire libre. Los grupos sensibles deberían quedarse en casa con las ventanas cerradas.
+aqi-advice-very-poor = Evita la actividad al aire libre. Mantén las ventanas cerradas y usa un purificador de aire si tienes uno.
+
+chart-caption = 📈 Temperatura y probabilidad de precipitación en las próximas 48 horas en <b>{ $city }</b>
+
+forecast-summary = { $emoji } { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit }, { $description }
+inline-title = { $day } — { $city }
+
+service-error = Error del servicio, contacta con los desarrolladores
+user-not-found = No te hemos encontrado. Prueba a reiniciar el bot con el comando /start.
+api-fetch-error = No se pudieron obtener los datos del tiempo. Inténtalo más tarde.
+weather-city-not-found = 🏙️ El servicio meteorológico no conoce esta ciudad. Revisa el nombre o elige otra ciudad.
+weather-invalid-api-key = 🔑 El servicio meteorológico rechazó la clave API del bot. Contacta con los desarrolladores.
+weather-quota-exceeded = ⏳ Se ha alcanzado el límite de solicitudes del servicio meteorológico. Inténtalo más tarde.
+weather-timeout = ⌛ El servicio meteorológico tardó demasiado en responder. Inténtalo de nuevo.
+weather-invalid-response = ⚠️ El servicio meteorológico devolvió una respuesta inesperada. Inténtalo más tarde.
+telegram-error = ❌ No se pudo actualizar el mensaje. Inténtalo de nuevo.
+stale-data = <i>⚠️ El servicio meteorológico no está disponible, datos de { $time }</i>
+no-forecast-data = No hay pronóstico disponible para el periodo seleccionado
+missing-message = Error al procesar la solicitud.
+throttled = ⏳ Demasiadas solicitudes. Espera { $seconds } { $seconds ->
+        [one] segundo
+       *[other] segundos
+    } e inténtalo de nuevo.
+
+back = ⬅️ Atrás
+
+select-language = Elegir idioma
+select-units = Unidades de medida
+change-city-button = 🏙️ Cambiar ciudad
+uv-alerts-button = ☀️ Alertas UV
+change-city = 🏙️ Escribe el nombre de la nueva ciudad
+change-city-group = 🏙️ Un administrador debe responder a este mensaje con el nombre de la nueva ciudad
+
+
+settings-hub =
+    ⚙️ <b>Ajustes</b>
+
+    Aquí puedes cambiar el idioma y otras preferencias del bot 👇
+
+
+settings-language-hub =
+    🌐 <b>Idioma</b>
+
+    Elige el idioma que usará el bot 👇
+
+settings-units-hub =
+    📏 <b>Unidades de medida</b>
+
+    Elige las unidades que usará el bot 👇
+
+
+temperature-button = 🌡️ Unidad de temperatura
+wind-button = 💨 Unidad de velocidad del viento
+
+settings-units-temp-hub =
+    🌡️ <b>Unidades de temperatura</b>
+
+    Elige la unidad para mostrar la temperatura 👇
+
+temperature-unit-no-change = La unidad de temperatura seleccionada ya está activa.
+temperature-unit-success = ¡Unidad de temperatura actualizada!
+
+settings-units-speed-hub =
+    💨 <b>Unidades de velocidad</b>
+
+    Elige la unidad para mostrar la velocidad del viento 👇
+
+speed-unit-no-change = La unidad de velocidad seleccionada ya está activa.
+speed-unit-success = ¡Unidad de velocidad actualizada!
+
+
+condition-thunderstorm = tormenta
+condition-drizzle = llovizna
+condition-rain = lluvia
+condition-freezing-rain = lluvia helada
+condition-shower-rain = chubascos
+condition-snow = nieve
+condition-sleet = aguanieve
+condition-shower-snow = chubascos de nieve
+condition-mist = neblina
+condition-smoke = humo
+condition-haze = calima
+condition-dust = polvo
+condition-fog = niebla
+condition-ash = ceniza volcánica
+condition-squall = turbonadas
+condition-tornado = tornado
+condition-clear = cielo despejado
+condition-few-clouds = algunas nubes
+condition-scattered-clouds = nubes dispersas
+condition-broken-clouds = nuboso
+condition-overcast = cubierto
+condition-unknown = sin datos
+
+
+admin-stats =
+    📊 <b>Estadísticas</b>
+
+    Usuarios: { $users }
+    Grupos: { $groups }
+    { $days ->
+        [one] Activos el último día
+       *[other] Activos en los últimos { $days } días
+    }: { $active }
+    Bloquearon el bot: { $inactive }
+
+    <b>Idiomas</b>
+    { $languages }
+
+    <b>Unidades de temperatura</b>
+    { $temperature }
+
+    <b>Unidades de velocidad</b>
+    { $speed }
+
+admin-user =
+    👤 <b>Registro { $id }</b>
+
+    Ciudad: { $city }
+    Idioma: { $language }
+    Unidad de temperatura: { $temperature }
+    Unidad de velocidad: { $speed }
+    Creado: { $created }
+    Actualizado: { $updated }
+    Última actividad: { $active }
+
+admin-user-not-found = No hay ningún registro con el ID { $id }.
+admin-user-usage = Uso: /user &lt;id&gt;
+admin-broadcast-usage = Uso: /broadcast <texto>
+admin-broadcast-progress = 📣 Enviando… { $sent } de { $total } enviados, { $blocked } bloqueados, { $failed } fallidos.
+admin-broadcast-done = ✅ Difusión terminada: { $sent } de { $total } enviados, { $blocked } bloqueados, { $failed } fallidos.
+
+privacy-private-only = 🔒 Envía este comando en un chat privado con el bot.
+privacy-no-data = El bot no guarda ningún dato sobre ti.
+delete-confirm =
+    ⚠️ Esto elimina para siempre todo lo que el bot guarda sobre ti: tu ciudad, la configuración de idioma y unidades y las fechas de actividad.
+
+    ¿Eliminar tus datos?
+delete-confirm-button = 🗑 Eliminar mis datos
+delete-cancel-button = Cancelar
+delete-done = ✅ Tus datos se han eliminado. Envía /start para volver a usar el bot.
+delete-cancelled = Eliminación cancelada, tus datos se conservan.
+export-caption = 📦 Todo lo que el bot guarda sobre ti.
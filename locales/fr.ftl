@@ -0,0 +1,259 @@
+## Language metadata, shown in the language selection, sent to the weather provider
+## and used to format numbers and dates
+language-name = Français
+language-flag = 🇫🇷
+language-provider-code = fr
+language-locale = fr_FR
+
+## Messages
+
+hub-message =
+    👋 <b>Bonjour !</b>
+
+    <b>Votre ville</b> : { $city }
+
+    🔹 Choisissez une action ci-dessous ⬇️
+
+
+
+start =
+    👋🏻 Bonjour !
+
+    Pour obtenir la météo, saisissez le nom de votre ville
+
+start-group =
+    👋🏻 Bonjour !
+
+    Pour recevoir la météo dans ce groupe, un administrateur doit répondre à ce message avec le nom de la ville
+
+
+language-no-change = La langue choisie est déjà active.
+language-success = Langue mise à jour !
+
+save-city-success = ✅ La ville a bien été enregistrée !
+validation-city = ⚠️ Veuillez saisir une ville valide. Réessayez.
+saving-error = ❌ Erreur d'enregistrement. Veuillez réessayer.
+error = ❌ Une erreur s'est produite. Veuillez réessayer.
+group-admin-only = Seuls les administrateurs du groupe peuvent modifier ses paramètres.
+
+now = Maintenant
+today = Aujourd'hui
+tomorrow = Demain
+sun-button = 🌅 Soleil
+air-quality-button = 🌬️ Qualité de l'air
+chart-button = 📈 Graphique
+settings = Paramètres
+
+weather =
+    🌤️ <b>Météo à { $city } pour { $day }</b>{ $date }
+
+    { $emoji } { $description }
+
+    🌡️ <b>Température</b> : { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit } (ressentie { NUMBER($feels_like, maximumFractionDigits: 0) }{ $temp_unit })
+    💧 <b>Humidité</b> : { $humidity } %
+    💨 <b>Vent</b> : { $wind_speed }{ $uv }{ $sun }
+
+    <i>Bonne journée !</i> ☀️
+
+
+weather-wind-speed-kmh = km/h
+
+weather-wind-speed-unknown = indisponible
+
+weather-date-line = 📅 { DATETIME($date, weekday: "long", day: "numeric", month: "long") }
+weather-sun-line = 🌅 <b>Soleil</b> : { $sunrise } – { $sunset }
+weather-uv-line = ☀️ <b>Indice UV</b> : { $uv } { $emoji } { $risk }. { $advice }
+
+sun =
+    🌅 <b>Soleil à { $city }</b>
+
+    🌄 <b>Lever</b> : { $sunrise }
+    🌇 <b>Coucher</b> : { $sunset }
+    ⏳ <b>Durée du jour</b> : { $hours } { $hours ->
+        [one] heure
+       *[other] heures
+    } { $minutes } min
+
+    <i>Les heures sont indiquées à l'heure locale de la ville</i>
+
+uv-low = faible
+uv-moderate = modéré
+uv-high = élevé
+uv-very-high = très élevé
+uv-extreme = extrême
+
+uv-advice-low = Aucune protection nécessaire.
+uv-advice-moderate = Portez des lunettes de soleil et une crème solaire SPF 30+.
+uv-advice-high = Utilisez une crème SPF 30+, portez un chapeau et restez à l'ombre vers midi.
+uv-advice-very-high = Utilisez une crème SPF 50+, renouvelez-la toutes les 2 heures et évitez le soleil de 11 h à 16 h.
+uv-advice-extreme = Utilisez une crème SPF 50+ et restez à l'intérieur vers midi si possible.
+
+uv-alert = ☀️ <b>{ $city }</b> : L'indice UV aujourd'hui est de { $uv } ({ $risk }). { $advice }
+uv-alerts-enabled = 🔔 Les alertes d'indice UV élevé sont activées.
+uv-alerts-disabled = 🔕 Les alertes d'indice UV élevé sont désactivées.
+
+air-quality =
+    🌬️ <b>Qualité de l'air à { $city }</b>
+
+    { $emoji } <b>{ $category }</b> (AQI { $aqi } sur 5)
+
+    <b>PM2.5</b> : { NUMBER($pm2_5, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>PM10</b> : { NUMBER($pm10, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>O₃</b> : { NUMBER($o3, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>NO₂</b> : { NUMBER($no2, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+
+    💡 { $advice }
+
+aqi-good = Bonne
+aqi-fair = Correcte
+aqi-moderate = Moyenne
+aqi-poor = Mauvaise
+aqi-very-poor = Très mauvaise
+
+aqi-advice-good = L'air est excellent. Profitez des activités en plein air !
+aqi-advice-fair = La qualité de l'air est acceptable. Les personnes particulièrement sensibles devraient limiter les efforts prolongés en extérieur.
+aqi-advice-moderate = Les personnes asthmatiques ou souffrant de maladies cardiaques ou pulmonaires devraient réduire les efforts prolongés en extérieur et garder leur inhalateur à portée de main.
+aqi-advice-poor = Limitez les activités en extérieur. Les personnes sensibles devraient rester à l'intérieur, fenêtres fermées.
+aqi-advice-very-poor = Évitez les activités en extérieur. Gardez les fenêtres fermées et utilisez un purificateur d'air si vous en avez un.
+
+chart-caption = 📈 Température et probabilité de précipitations pour les 48 prochaines heures à <b>{ $city }</b>
+
+forecast-summary = { $emoji } { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit }, { $description }
+inline-title = { $day } — { $city }
+
+service-error = Erreur du service, veuillez contacter les développeurs
+user-not-found = Vous n'avez pas été trouvé. Essayez de redémarrer le bot avec la commande /start.
+api-fetch-error = Impossible d'obtenir les données météo. Veuillez réessayer plus tard.
+weather-city-not-found = 🏙️ Le service météo ne connaît pas cette ville. Vérifiez le nom ou choisissez une autre ville.
+weather-invalid-api-key = 🔑 Le service météo a refusé la clé API du bot. Veuillez contacter les développeurs.
+weather-quota-exceeded = ⏳ La limite de requêtes du service météo est atteinte. Veuillez réessayer plus tard.
+weather-timeout = ⌛ Le service météo a mis trop de temps à répondre. Veuillez réessayer.
+weather-invalid-response = ⚠️ Le service météo a renvoyé une réponse inattendue. Veuillez réessayer plus tard.
+telegram-error = ❌ Impossible de mettre à jour le message. Veuillez réessayer.
+stale-data = <i>⚠️ Le service météo est indisponible, données du { $time }</i>
+no-forecast-data = Prévisions indisponibles pour la période choisie
+missing-message = Erreur lors du traitement de la demande.
+throttled = ⏳ Trop de requêtes. Patientez { $seconds } { $seconds ->
+        [one] seconde
+       *[other] secondes
+    } et réessayez.
+
+back = ⬅️ Retour
+
+select-language = Choisir la langue
+select-units = Unités de mesure
+change-city-button = 🏙️ Changer de ville
+uv-alerts-button = ☀️ Alertes UV
+change-city = 🏙️ Saisissez le nom de la nouvelle ville
+change-city-group = 🏙️ Un administrateur doit répondre à ce message avec le nom de la nouvelle ville
+
+
+settings-hub =
+    ⚙️ <b>Paramètres</b>
+
+    Ici, vous pouvez changer la langue et d'autres préférences du bot 👇
+
+
+settings-language-hub =
+    🌐 <b>Langue</b>
+
+    Choisissez la langue du bot 👇
+
+settings-units-hub =
+    📏 <b>Unités de mesure</b>
+
+    Choisissez les unités utilisées par le bot 👇
+
+
+temperature-button = 🌡️ Unité de température
+wind-button = 💨 Unité de vitesse du vent
+
+settings-units-temp-hub =
+    🌡️ <b>Unités de température</b>
+
+    Choisissez l'unité d'affichage de la température 👇
+
+temperature-unit-no-change = L'unité de température choisie est déjà active.
+temperature-unit-success = Unité de température mise à jour !
+
+settings-units-speed-hub =
+    💨 <b>Unités de vitesse</b>
+
+    Choisissez l'unité d'affichage de la vitesse du vent 👇
+
+speed-unit-no-change = L'unité de vitesse choisie est déjà active.
+speed-unit-success = Unité de vitesse mise à jour !
+
+
+condition-thunderstorm = orage
+condition-drizzle = bruine
+condition-rain = pluie
+condition-freezing-rain = pluie verglaçante
+condition-shower-rain = averses
+condition-snow = neige
+condition-sleet = neige fondue
+condition-shower-snow = averses de neige
+condition-mist = brume
+condition-smoke = fumée
+condition-haze = brume sèche
+condition-dust = poussière
+condition-fog = brouillard
+condition-ash = cendres volcaniques
+condition-squall = grains
+condition-tornado = tornade
+condition-clear = ciel dégagé
+condition-few-clouds = quelques nuages
+condition-scattered-clouds = nuages épars
+condition-broken-clouds = nuageux
+condition-overcast = couvert
+condition-unknown = pas de données
+
+
+admin-stats =
+    📊 <b>Statistiques</b>
+
+    Utilisateurs : { $users }
+    Groupes : { $groups }
+    { $days ->
+        [one] Actifs le dernier jour
+       *[other] Actifs ces { $days } derniers jours
+    } : { $active }
+    Ont bloqué le bot : { $inactive }
+
+    <b>Langues</b>
+    { $languages }
+
+    <b>Unités de température</b>
+    { $temperature }
+
+    <b>Unités de vitesse</b>
+    { $speed }
+
+admin-user =
+    👤 <b>Enregistrement { $id }</b>
+
+    Ville : { $city }
+    Langue : { $language }
+    Unité de température : { $temperature }
+    Unité de vitesse : { $speed }
+    Créé : { $created }
+    Mis à jour : { $updated }
+    Dernière activité : { $active }
+
+admin-user-not-found = Aucun enregistrement avec l'ID { $id }.
+admin-user-usage = Utilisation : /user &lt;id&gt;
+admin-broadcast-usage = Utilisation : /broadcast <texte>
+admin-broadcast-progress = 📣 Diffusion… { $sent } sur { $total } envoyés, { $blocked } bloqués, { $failed } échecs.
+admin-broadcast-done = ✅ Diffusion terminée : { $sent } sur { $total } envoyés, { $blocked } bloqués, { $failed } échecs.
+
+privacy-private-only = 🔒 Envoyez cette commande dans une conversation privée avec le bot.
+privacy-no-data = Le bot ne conserve aucune donnée vous concernant.
+delete-confirm =
+    ⚠️ Cela supprime définitivement tout ce que le bot conserve sur vous : votre ville, vos paramètres de langue et d'unités et les dates d'activité.
+
+    Supprimer vos données ?
+delete-confirm-button = 🗑 Supprimer mes données
+delete-cancel-button = Annuler
+delete-done = ✅ Vos données ont été supprimées. Envoyez /start pour utiliser à nouveau le bot.
+delete-cancelled = Suppression annulée, vos données sont conservées.
+export-caption = 📦 Tout ce que le bot conserve sur vous.
@@ -0,0 +1,261 @@
+## Language metadata, shown in the language selection, sent to the weather provider
+## and used to format numbers and dates
+language-name = Polski
+language-flag = 🇵🇱
+language-provider-code = pl
+language-locale = pl_PL
+
+## Messages
+
+hub-message =
+    👋 <b>Cześć!</b>
+
+    <b>Twoje miasto</b>: { $city }
+
+    🔹 Wybierz działanie poniżej ⬇️
+
+
+
+start =
+    👋🏻 Cześć!
+
+    Aby otrzymać prognozę pogody, wpisz nazwę swojego miasta
+
+start-group =
+    👋🏻 Cześć!
+
+    Aby otrzymywać prognozę pogody w tej grupie, administrator powinien odpowiedzieć na tę wiadomość nazwą miasta
+
+
+language-no-change = Wybrany język jest już aktywny.
+language-success = Język został zmieniony!
+
+save-city-success = ✅ Miasto zostało zapisane!
+validation-city = ⚠️ Wpisz poprawną nazwę miasta. Spróbuj ponownie.
+saving-error = ❌ Błąd zapisu. Spróbuj ponownie.
+error = ❌ Coś poszło nie tak. Spróbuj ponownie.
+group-admin-only = Tylko administratorzy grupy mogą zmieniać jej ustawienia.
+
+now = Teraz
+today = Dziś
+tomorrow = Jutro
+sun-button = 🌅 Słońce
+air-quality-button = 🌬️ Jakość powietrza
+chart-button = 📈 Wykres
+settings = Ustawienia
+
+weather =
+    🌤️ <b>Pogoda w { $city } na { $day }</b>{ $date }
+
+    { $emoji } { $description }
+
+    🌡️ <b>Temperatura</b>: { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit } (odczuwalna { NUMBER($feels_like, maximumFractionDigits: 0) }{ $temp_unit })
+    💧 <b>Wilgotność</b>: { $humidity }%
+    💨 <b>Wiatr</b>: { $wind_speed }{ $uv }{ $sun }
+
+    <i>Miłego dnia!</i> ☀️
+
+
+weather-wind-speed-kmh = km/h
+
+weather-wind-speed-unknown = brak danych
+
+weather-date-line = 📅 { DATETIME($date, weekday: "long", day: "numeric", month: "long") }
+weather-sun-line = 🌅 <b>Słońce</b>: { $sunrise } – { $sunset }
+weather-uv-line = ☀️ <b>Indeks UV</b>: { $uv } { $emoji } { $risk }. { $advice }
+
+sun =
+    🌅 <b>Słońce w { $city }</b>
+
+    🌄 <b>Wschód</b>: { $sunrise }
+    🌇 <b>Zachód</b>: { $sunset }
+    ⏳ <b>Długość dnia</b>: { $hours } { $hours ->
+        [one] godzina
+        [few] godziny
+       *[many] godzin
+    } { $minutes } min
+
+    <i>Godziny podano w czasie lokalnym miasta</i>
+
+uv-low = niski
+uv-moderate = umiarkowany
+uv-high = wysoki
+uv-very-high = bardzo wysoki
+uv-extreme = ekstremalny
+
+uv-advice-low = Ochrona nie jest potrzebna.
+uv-advice-moderate = Noś okulary przeciwsłoneczne i używaj kremu z filtrem SPF 30+.
+uv-advice-high = Używaj kremu SPF 30+, noś nakrycie głowy i w południe szukaj cienia.
+uv-advice-very-high = Używaj kremu SPF 50+, nakładaj go co 2 godziny i unikaj słońca od 11:00 do 16:00.
+uv-advice-extreme = Używaj kremu SPF 50+ i w miarę możliwości zostań w południe w domu.
+
+uv-alert = ☀️ <b>{ $city }</b>: Indeks UV dziś wynosi { $uv } ({ $risk }). { $advice }
+uv-alerts-enabled = 🔔 Alerty o wysokim indeksie UV są włączone.
+uv-alerts-disabled = 🔕 Alerty o wysokim indeksie UV są wyłączone.
+
+air-quality =
+    🌬️ <b>Jakość powietrza w { $city }</b>
+
+    { $emoji } <b>{ $category }</b> (AQI { $aqi } z 5)
+
+    <b>PM2.5</b>: { NUMBER($pm2_5, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>PM10</b>: { NUMBER($pm10, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>O₃</b>: { NUMBER($o3, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+    <b>NO₂</b>: { NUMBER($no2, minimumFractionDigits: 1, maximumFractionDigits: 1) } μg/m³
+
+    💡 { $advice }
+
+aqi-good = Dobra
+aqi-fair = Dostateczna
+aqi-moderate = Umiarkowana
+aqi-poor = Zła
+aqi-very-poor = Bardzo zła
+
+aqi-advice-good = Powietrze jest czyste. Korzystaj z aktywności na świeżym powietrzu!
+aqi-advice-fair = Jakość powietrza jest akceptowalna. Osoby szczególnie wrażliwe powinny ograniczyć długi wysiłek na zewnątrz.
+aqi-advice-moderate = Osoby z astmą, chorobami serca lub płuc powinny ograniczyć długi wysiłek na zewnątrz i mieć inhalator pod ręką.
+aqi-advice-poor = Ogranicz aktywność na zewnątrz. Osoby wrażliwe powinny zostać w domu i nie otwierać okien.
+aqi-advice-very-poor = Unikaj aktywności na zewnątrz. Nie otwieraj okien i włącz oczyszczacz powietrza, jeśli go masz.
+
+chart-caption = 📈 Temperatura i prawdopodobieństwo opadów na najbliższe 48 godzin w <b>{ $city }</b>
+
+forecast-summary = { $emoji } { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit }, { $description }
+inline-title = { $day } — { $city }
+
+service-error = Błąd usługi, skontaktuj się z twórcami bota
+user-not-found = Nie znaleziono Cię. Spróbuj uruchomić bota ponownie poleceniem /start.
+api-fetch-error = Nie udało się pobrać danych pogodowych. Spróbuj później.
+weather-city-not-found = 🏙️ Serwis pogodowy nie zna tego miasta. Sprawdź nazwę lub wybierz inne miasto.
+weather-invalid-api-key = 🔑 Serwis pogodowy odrzucił klucz API bota. Skontaktuj się z twórcami bota.
+weather-quota-exceeded = ⏳ Wyczerpano limit zapytań do serwisu pogodowego. Spróbuj później.
+weather-timeout = ⌛ Serwis pogodowy odpowiadał zbyt długo. Spróbuj ponownie.
+weather-invalid-response = ⚠️ Serwis pogodowy zwrócił nieoczekiwaną odpowiedź. Spróbuj później.
+telegram-error = ❌ Nie udało się zaktualizować wiadomości. Spróbuj ponownie.
+stale-data = <i>⚠️ Serwis pogodowy jest niedostępny, pokazano dane z { $time }</i>
+no-forecast-data = Prognoza pogody na wybrany okres jest niedostępna
+missing-message = Błąd przetwarzania żądania.
+throttled = ⏳ Zbyt wiele zapytań. Poczekaj { $seconds } { $seconds ->
+        [one] sekundę
+        [few] sekundy
+       *[many] sekund
+    } i spróbuj ponownie.
+
+back = ⬅️ Wstecz
+
+select-language = Wybierz język
+select-units = Ustawienia jednostek
+change-city-button = 🏙️ Zmień miasto
+uv-alerts-button = ☀️ Alerty UV
+change-city = 🏙️ Wpisz nazwę nowego miasta
+change-city-group = 🏙️ Administrator powinien odpowiedzieć na tę wiadomość nazwą nowego miasta
+
+
+settings-hub =
+    ⚙️ <b>Ustawienia</b>
+
+    Tutaj możesz zmienić język i inne ustawienia bota 👇
+
+
+settings-language-hub =
+    🌐 <b>Ustawienia języka</b>
+
+    Wybierz język, w którym ma odpowiadać bot 👇
+
+settings-units-hub =
+    📏 <b>Ustawienia jednostek</b>
+
+    Wybierz jednostki, których ma używać bot 👇
+
+
+temperature-button = 🌡️ Jednostka temperatury
+wind-button = 💨 Jednostka prędkości wiatru
+
+settings-units-temp-hub =
+    🌡️ <b>Jednostki temperatury</b>
+
+    Wybierz jednostki wyświetlania temperatury 👇
+
+temperature-unit-no-change = Wybrana jednostka temperatury jest już aktywna.
+temperature-unit-success = Jednostka temperatury została zmieniona!
+
+settings-units-speed-hub =
+    💨 <b>Jednostki prędkości</b>
+
+    Wybierz jednostki wyświetlania prędkości wiatru 👇
+
+speed-unit-no-change = Wybrana jednostka prędkości jest już aktywna.
+speed-unit-success = Jednostka prędkości została zmieniona!
+
+
+condition-thunderstorm = burza
+condition-drizzle = mżawka
+condition-rain = deszcz
+condition-freezing-rain = marznący deszcz
+condition-shower-rain = przelotny deszcz
+condition-snow = śnieg
+condition-sleet = deszcz ze śniegiem
+condition-shower-snow = przelotny śnieg
+condition-mist = zamglenie
+condition-smoke = dym
+condition-haze = mgiełka
+condition-dust = pył
+condition-fog = mgła
+condition-ash = pył wulkaniczny
+condition-squall = szkwały
+condition-tornado = tornado
+condition-clear = bezchmurnie
+condition-few-clouds = małe zachmurzenie
+condition-scattered-clouds = umiarkowane zachmurzenie
+condition-broken-clouds = duże zachmurzenie
+condition-overcast = pochmurno
+condition-unknown = brak danych
+
+
+admin-stats =
+    📊 <b>Statystyki</b>
+
+    Użytkownicy: { $users }
+    Grupy: { $groups }
+    { $days ->
+        [one] Aktywni w ciągu ostatniego dnia
+       *[other] Aktywni w ciągu ostatnich { $days } dni
+    }: { $active }
+    Zablokowali bota: { $inactive }
+
+    <b>Języki</b>
+    { $languages }
+
+    <b>Jednostki temperatury</b>
+    { $temperature }
+
+    <b>Jednostki prędkości</b>
+    { $speed }
+
+admin-user =
+    👤 <b>Rekord { $id }</b>
+
+    Miasto: { $city }
+    Język: { $language }
+    Jednostka temperatury: { $temperature }
+    Jednostka prędkości: { $speed }
+    Utworzono: { $created }
+    Zaktualizowano: { $updated }
+    Ostatnia aktywność: { $active }
+
+admin-user-not-found = Brak rekordu o ID { $id }.
+admin-user-usage = Użycie: /user &lt;id&gt;
+admin-broadcast-usage = Użycie: /broadcast <tekst>
+admin-broadcast-progress = 📣 Wysyłanie… wysłano { $sent } z { $total }, zablokowane: { $blocked }, błędy: { $failed }.
+admin-broadcast-done = ✅ Wysyłka zakończona: wysłano { $sent } z { $total }, zablokowane: { $blocked }, błędy: { $failed }.
+
+privacy-private-only = 🔒 Wyślij to polecenie w prywatnym czacie z botem.
+privacy-no-data = Bot nie przechowuje żadnych danych o Tobie.
+delete-confirm =
+    ⚠️ Spowoduje to trwałe usunięcie wszystkiego, co bot o Tobie przechowuje: miasta, ustawień języka i jednostek oraz znaczników czasu aktywności.
+
+    Usunąć Twoje dane?
+delete-confirm-button = 🗑 Usuń moje dane
+delete-cancel-button = Anuluj
+delete-done = ✅ Twoje dane zostały usunięte. Wyślij /start, aby znów korzystać z bota.
+delete-cancelled = Usuwanie anulowane, Twoje dane zostały zachowane.
+export-caption = 📦 Wszystko, co bot o Tobie przechowuje.
@@ -1,3 +1,12 @@
+## Language metadata, shown in the language selection, sent to the weather provider
+## and used to format numbers and dates
+language-name = Українська
+language-flag = 🇺🇦
+language-provider-code = uk
+language-locale = uk_UA
+
+## Messages
+
 hub-message =
     👋 <b>Привіт!</b>
 
@@ -12,6 +21,11 @@ start =
 
      Щоб дізнатися прогноз погоди, введіть назву вашого міста
 
+start-group =
+    👋🏻 Привіт!
+
+    Щоб отримувати прогноз погоди в цій групі, адміністратор має відповісти на це повідомлення назвою міста
+
 
 language-no-change = Обрана мова вже активна.
 language-success = Мову успішно змінено!
@@ -20,19 +34,24 @@ save-city-success = ✅ Місто збережено успішно!
 validation-city = ⚠️ Будь-ласка введіть валідне місто. Спробуйте знову.
 saving-error = ❌ Помилка при збережені. Спробуйте ще раз.
 error = Щось пішло не так. Спробуйте ще раз.
+group-admin-only = Лише адміністратори групи можуть змінювати налаштування групи.
 
+now = Зараз
 today = Сьогодні
 tomorrow = Завтра
+sun-button = 🌅 Сонце
+air-quality-button = 🌬️ Якість повітря
+chart-button = 📈 Графік
 settings = Налаштування
 
 weather =
-    🌤️ <b>Погода в { $city } на { $day }</b>
+    🌤️ <b>Погода в { $city } на { $day }</b>{ $date }
 
     { $emoji } { $description }
 
-    🌡️ <b>Температура</b>: { $temp }{ $temp_unit } (відчувається як { $feels_like }{ $temp_unit })
+    🌡️ <b>Температура</b>: { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit } (відчувається як { NUMBER($feels_like, maximumFractionDigits: 0) }{ $temp_unit })
     💧 <b>Вологість</b>: { $humidity }%
-    💨 <b>Вітер</b>: { $wind_speed }
+    💨 <b>Вітер</b>: { $wind_speed }{ $uv }{ $sun }
 
     <i>Гарного дня!</i> ☀️
 
@@ -41,17 +60,87 @@ weather-wind-speed-kmh = км/год
 
 weather-wind-speed-unknown = відсутній
 
+weather-date-line = 📅 { DATETIME($date, weekday: "long", day: "numeric", month: "long") }
+weather-sun-line = 🌅 <b>Сонце</b>: { $sunrise } – { $sunset }
+weather-uv-line = ☀️ <b>УФ-індекс</b>: { $uv } { $emoji } { $risk }. { $advice }
+
+sun =
+    🌅 <b>Сонце в місті { $city }</b>
+
+    🌄 <b>Схід</b>: { $sunrise }
+    🌇 <b>Захід</b>: { $sunset }
+    ⏳ <b>Тривалість дня</b>: { $hours } год { $minutes } хв
+
+    <i>Час указано за місцевим часом міста</i>
+
+uv-low = низький
+uv-moderate = помірний
+uv-high = високий
+uv-very-high = дуже високий
+uv-extreme = екстремальний
+
+uv-advice-low = Захист не потрібен.
+uv-advice-moderate = Одягніть сонцезахисні окуляри та використовуйте крем SPF 30+.
+uv-advice-high = Використовуйте крем SPF 30+, одягніть капелюх і шукайте тінь опівдні.
+uv-advice-very-high = Використовуйте крем SPF 50+, оновлюйте його кожні 2 години та уникайте сонця з 11:00 до 16:00.
+uv-advice-extreme = Використовуйте крем SPF 50+ і за можливості залишайтеся в приміщенні опівдні.
+
+uv-alert = ☀️ <b>{ $city }</b>: УФ-індекс сьогодні { $uv } ({ $risk }). { $advice }
+uv-alerts-enabled = 🔔 Сповіщення про високий УФ-індекс увімкнено.
+uv-alerts-disabled = 🔕 Сповіщення про високий УФ-індекс вимкнено.
+
+air-quality =
+    🌬️ <b>Якість повітря в місті { $city }</b>
+
+    { $emoji } <b>{ $category }</b> (AQI { $aqi } з 5)
+
+    <b>PM2.5</b>: { NUMBER($pm2_5, minimumFractionDigits: 1, maximumFractionDigits: 1) } мкг/м³
+    <b>PM10</b>: { NUMBER($pm10, minimumFractionDigits: 1, maximumFractionDigits: 1) } мкг/м³
+    <b>O₃</b>: { NUMBER($o3, minimumFractionDigits: 1, maximumFractionDigits: 1) } мкг/м³
+    <b>NO₂</b>: { NUMBER($no2, minimumFractionDigits: 1, maximumFractionDigits: 1) } мкг/м³
+
+    💡 { $advice }
+
+aqi-good = Добра
+aqi-fair = Задовільна
+aqi-moderate = Помірна
+aqi-poor = Погана
+aqi-very-poor = Дуже погана
+
+aqi-advice-good = Повітря чисте. Гарний час для прогулянок!
+aqi-advice-fair = Якість повітря прийнятна. Особливо чутливим людям варто обмежити тривале фізичне навантаження надворі.
+aqi-advice-moderate = Людям з астмою, хворобами серця чи легень варто зменшити тривале навантаження надворі та мати інгалятор під рукою.
+aqi-advice-poor = Обмежте перебування надворі. Чутливим групам краще залишатися вдома із зачиненими вікнами.
+aqi-advice-very-poor = Уникайте перебування надворі. Тримайте вікна зачиненими та за можливості увімкніть очищувач повітря.
+
+chart-caption = 📈 Температура та ймовірність опадів на найближчі 48 годин у місті <b>{ $city }</b>
+
+forecast-summary = { $emoji } { NUMBER($temp, maximumFractionDigits: 0) }{ $temp_unit }, { $description }
+inline-title = { $day } — { $city }
+
 service-error = Помилка сервісу, зверніться до розробників
 user-not-found = Вас не знайдено. Спробуйте перезапустити бота використовуючи команду /start.
 api-fetch-error = Не вдалося отримати дані про погоду. Спробуйте пізніше
+weather-city-not-found = 🏙️ Сервіс погоди не знає цього міста. Перевірте назву або оберіть інше місто.
+weather-invalid-api-key = 🔑 Сервіс погоди відхилив API-ключ бота. Зверніться до розробників.
+weather-quota-exceeded = ⏳ Вичерпано ліміт запитів до сервісу погоди. Спробуйте пізніше.
+weather-timeout = ⌛ Сервіс погоди не відповів вчасно. Спробуйте ще раз.
+weather-invalid-response = ⚠️ Сервіс погоди повернув неочікувану відповідь. Спробуйте пізніше.
+telegram-error = ❌ Не вдалося оновити повідомлення. Спробуйте ще раз.
+stale-data = <i>⚠️ Сервіс погоди недоступний, дані станом на { $time }</i>
 no-forecast-data = Прогноз погоди недоступний для обраного періоду
 missing-message = Помилка обробки запиту.
+throttled = ⏳ Забагато запитів. Зачекайте { $seconds } с і спробуйте ще раз.
 
 
 back = ⬅️ Назад
 
 select-language = Обрати мову
 select-units = Одиниці вимірювання
+change-city-button = 🏙️ Змінити місто
+uv-alerts-button = ☀️ Сповіщення про УФ
+change-city = 🏙️ Введіть назву нового міста
+change-city-group = 🏙️ Адміністратор має відповісти на це повідомлення назвою нового міста
 
 
 settings-hub = 
@@ -89,3 +178,74 @@ settings-units-speed-hub =
 
 speed-unit-no-change = Обрані одиниці швидкості вже активні.
 speed-unit-success = Одиниці швидкості успішно оновлено!
+
+
+condition-thunderstorm = гроза
+condition-drizzle = мряка
+condition-rain = дощ
+condition-freezing-rain = крижаний дощ
+condition-shower-rain = злива
+condition-snow = сніг
+condition-sleet = дощ зі снігом
+condition-shower-snow = снігопад
+condition-mist = імла
+condition-smoke = дим
+condition-haze = серпанок
+condition-dust = пил
+condition-fog = туман
+condition-ash = вулканічний попіл
+condition-squall = шквали
+condition-tornado = торнадо
+condition-clear = ясно
+condition-few-clouds = невелика хмарність
+condition-scattered-clouds = мінлива хмарність
+condition-broken-clouds = хмарно з проясненнями
+condition-overcast = похмуро
+condition-unknown = немає даних
+
+
+admin-stats =
+    📊 <b>Статистика</b>
+
+    Користувачі: { $users }
+    Групи: { $groups }
+    Активні за останні { $days } днів: { $active }
+    Заблокували бота: { $inactive }
+
+    <b>Мови</b>
+    { $languages }
+
+    <b>Одиниці температури</b>
+    { $temperature }
+
+    <b>Одиниці швидкості</b>
+    { $speed }
+
+admin-user =
+    👤 <b>Запис { $id }</b>
+
+    Місто: { $city }
+    Мова: { $language }
+    Одиниця температури: { $temperature }
+    Одиниця швидкості: { $speed }
+    Створено: { $created }
+    Оновлено: { $updated }
+    Остання активність: { $active }
+
+admin-user-not-found = Запису з ID { $id } не знайдено.
+admin-user-usage = Використання: /user &lt;id&gt;
+admin-broadcast-usage = Використання: /broadcast <текст>
+admin-broadcast-progress = 📣 Розсилка… надіслано { $sent } з { $total }, заблокували: { $blocked }, помилок: { $failed }.
+admin-broadcast-done = ✅ Розсилку завершено: надіслано { $sent } з { $total }, заблокували: { $blocked }, помилок: { $failed }.
+
+privacy-private-only = 🔒 Надішліть цю команду в особистому чаті з ботом.
+privacy-no-data = Бот не зберігає жодних даних про вас.
+delete-confirm =
+    ⚠️ Буде безповоротно видалено все, що бот зберігає про вас: місто, налаштування мови та одиниць і час активності.
+
+    Видалити ваші дані?
+delete-confirm-button = 🗑 Видалити мої дані
+delete-cancel-button = Скасувати
+delete-done = ✅ Ваші дані видалено. Надішліть /start, щоб знову користуватися ботом.
+delete-cancelled = Видалення скасовано, ваші дані збережено.
+export-caption = 📦 Усе, що бот зберігає про вас.
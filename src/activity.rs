@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Minimum time between two activity writes for the same chat.
+const TOUCH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Number of checks between removing chats touched long ago from memory.
+const CLEANUP_EVERY: u32 = 1000;
+
+/// Remembers when chats were last marked active in the database,
+/// so busy chats don't cause a write on every update.
+#[derive(Default)]
+pub struct Activity {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// Time of the last activity write of every chat.
+    touched: HashMap<i64, Instant>,
+    checks: u32,
+}
+
+impl Activity {
+    /// Records an update from the chat.
+    ///
+    /// # Returns
+    /// `true` if the activity should be written, i.e. it was not written within [`TOUCH_INTERVAL`].
+    pub fn should_touch(&self, chat_id: i64) -> bool {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+
+        state.checks += 1;
+        if state.checks >= CLEANUP_EVERY {
+            state.checks = 0;
+            state.touched.retain(|_, touched| now.duration_since(*touched) < TOUCH_INTERVAL);
+        }
+
+        match state.touched.get(&chat_id) {
+            Some(touched) if now.duration_since(*touched) < TOUCH_INTERVAL => false,
+            _ => {
+                state.touched.insert(chat_id, now);
+                true
+            }
+        }
+    }
+
+    /// Forgets the chat, so its next update is written again.
+    pub fn forget(&self, chat_id: i64) {
+        self.state.lock().unwrap().touched.remove(&chat_id);
+    }
+}
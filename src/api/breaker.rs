@@ -0,0 +1,58 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Consecutive failures after which the circuit opens.
+const FAILURE_THRESHOLD: u32 = 5;
+
+/// How long the circuit stays open before a trial request is let through.
+const OPEN_FOR: Duration = Duration::from_secs(60);
+
+/// Stops calling a provider that keeps failing.
+///
+/// After [`FAILURE_THRESHOLD`] consecutive failures requests are rejected for [`OPEN_FOR`].
+/// Then a single trial request is allowed: success closes the circuit, failure opens it again.
+#[derive(Default)]
+pub struct CircuitBreaker {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Returns `true` if a request to the provider may be made.
+    pub fn allow(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        match state.open_until {
+            Some(until) if Instant::now() < until => false,
+            Some(_) => {
+                // Half-open: keep other requests out until the trial finishes
+                state.open_until = Some(Instant::now() + OPEN_FOR);
+                true
+            }
+            None => true,
+        }
+    }
+
+    /// Closes the circuit after a successful request.
+    pub fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.failures = 0;
+        state.open_until = None;
+    }
+
+    /// Counts a failed request and opens the circuit once the threshold is reached.
+    pub fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.failures += 1;
+        if state.failures >= FAILURE_THRESHOLD {
+            if state.open_until.is_none() {
+                tracing::warn!(failures = state.failures, "Weather provider circuit opened");
+            }
+            state.open_until = Some(Instant::now() + OPEN_FOR);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::api::breaker::CircuitBreaker;
+use crate::api::models::{AirQuality, WeatherResponse};
+use crate::api::provider::{ProviderError, WeatherProvider};
+use crate::api::Provider;
+use crate::enums::languages::Languages;
+use crate::logging::error_chain;
+use crate::metrics;
+
+/// How long expired entries are kept to be served while the provider is unavailable.
+const MAX_STALE_AGE: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// A cached value with the time it was fetched.
+struct Entry<T> {
+    fetched: Instant,
+    fetched_at: DateTime<Utc>,
+    value: T,
+}
+
+type Entries<T> = Mutex<HashMap<String, Entry<T>>>;
+
+/// Wraps a [`WeatherProvider`] and caches successful responses in memory.
+///
+/// Cities are matched case-insensitively. Errors are never cached.
+///
+/// When the provider is down (see [`ProviderError::is_outage`]) or its circuit is open,
+/// expired data up to [`MAX_STALE_AGE`] old is served instead, with `as_of` set to its fetch time.
+pub struct CachedProvider {
+    inner: Provider,
+    breaker: CircuitBreaker,
+    forecast_ttl: Duration,
+    air_quality_ttl: Duration,
+    forecasts: Entries<WeatherResponse>,
+    air_quality: Entries<AirQuality>,
+}
+
+impl CachedProvider {
+    /// Creates a caching wrapper around `inner`.
+    ///
+    /// # Arguments
+    /// - `inner` - provider to fetch missing or expired data from
+    /// - `forecast_ttl` - how long a forecast stays fresh
+    /// - `air_quality_ttl` - how long air quality data stays fresh
+    pub fn new(inner: Provider, forecast_ttl: Duration, air_quality_ttl: Duration) -> Self {
+        Self {
+            inner,
+            breaker: CircuitBreaker::default(),
+            forecast_ttl,
+            air_quality_ttl,
+            forecasts: Mutex::new(HashMap::new()),
+            air_quality: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns a fresh cached value, or fetches and stores a new one.
+    ///
+    /// Falls back to stale data if the provider is unavailable.
+    ///
+    /// # Arguments
+    /// - `cache` - cache name used in metrics
+    /// - `entries` - cache to look up
+    /// - `key` - cache key
+    /// - `ttl` - how long values stay fresh
+    /// - `fetch` - request to the inner provider
+    /// - `mark_stale` - sets the fetch time on a stale value
+    async fn get<T, F>(
+        &self,
+        cache: &str,
+        entries: &Entries<T>,
+        key: String,
+        ttl: Duration,
+        fetch: F,
+        mark_stale: fn(&mut T, DateTime<Utc>),
+    ) -> Result<T, ProviderError>
+    where
+        T: Clone,
+        F: Future<Output = Result<T, ProviderError>>,
+    {
+        if let Some(value) = lookup(cache, entries, &key, ttl) {
+            return Ok(value);
+        }
+
+        if !self.breaker.allow() {
+            return stale(cache, entries, &key, mark_stale).ok_or(ProviderError::Unavailable);
+        }
+
+        match fetch.await {
+            Ok(value) => {
+                self.breaker.record_success();
+                store(entries, key, value.clone());
+                Ok(value)
+            }
+            Err(e) if e.is_outage() => {
+                self.breaker.record_failure();
+                match stale(cache, entries, &key, mark_stale) {
+                    Some(value) => {
+                        tracing::warn!(error = %error_chain(&e), "Serving stale {cache} data");
+                        Ok(value)
+                    }
+                    None => Err(e),
+                }
+            }
+            Err(e) => {
+                // The provider answered, the request itself was wrong
+                self.breaker.record_success();
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Returns a fresh cached value, if any, and counts the hit or miss.
+fn lookup<T: Clone>(cache: &str, entries: &Entries<T>, key: &str, ttl: Duration) -> Option<T> {
+    let entries = entries.lock().unwrap();
+    let value = entries
+        .get(key)
+        .filter(|entry| entry.fetched.elapsed() < ttl)
+        .map(|entry| entry.value.clone());
+
+    metrics::record_cache(cache, value.is_some());
+    value
+}
+
+/// Returns an expired cached value up to [`MAX_STALE_AGE`] old, marked with the time it was fetched.
+fn stale<T: Clone>(cache: &str, entries: &Entries<T>, key: &str, mark_stale: fn(&mut T, DateTime<Utc>)) -> Option<T> {
+    let entries = entries.lock().unwrap();
+    // Entries are only pruned on store, so old ones may still be here
+    let entry = entries.get(key).filter(|entry| entry.fetched.elapsed() < MAX_STALE_AGE)?;
+
+    let mut value = entry.value.clone();
+    mark_stale(&mut value, entry.fetched_at);
+    metrics::record_stale(cache);
+    Some(value)
+}
+
+/// Stores a value and drops entries too old to be served even as stale data.
+fn store<T>(entries: &Entries<T>, key: String, value: T) {
+    let mut entries = entries.lock().unwrap();
+    entries.retain(|_, entry| entry.fetched.elapsed() < MAX_STALE_AGE);
+    entries.insert(key, Entry {
+        fetched: Instant::now(),
+        fetched_at: Utc::now(),
+        value,
+    });
+}
+
+#[async_trait]
+impl WeatherProvider for CachedProvider {
+    async fn forecast(&self, city: &str, lang: Languages) -> Result<WeatherResponse, ProviderError> {
+        let key = format!("{}:{}", lang.as_str(), city.trim().to_lowercase());
+        self.get(
+            "forecast",
+            &self.forecasts,
+            key,
+            self.forecast_ttl,
+            self.inner.forecast(city, lang),
+            |response, as_of| response.as_of = Some(as_of),
+        )
+            .await
+    }
+
+    async fn air_quality(&self, city: &str) -> Result<AirQuality, ProviderError> {
+        let key = city.trim().to_lowercase();
+        self.get(
+            "air_quality",
+            &self.air_quality,
+            key,
+            self.air_quality_ttl,
+            self.inner.air_quality(city),
+            |air, as_of| air.as_of = Some(as_of),
+        )
+            .await
+    }
+
+    async fn ping(&self) -> Result<(), ProviderError> {
+        self.inner.ping().await
+    }
+}
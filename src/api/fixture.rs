@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+
+use crate::api::models::{AirPollutionResponse, AirQuality, WeatherResponse};
+use crate::api::provider::{ProviderError, WeatherProvider};
+use crate::enums::languages::Languages;
+
+const FORECAST: &str = include_str!("../../fixtures/openweathermap/forecast.json");
+const AIR_POLLUTION: &str = include_str!("../../fixtures/openweathermap/air_pollution.json");
+
+const DT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// [`WeatherProvider`] serving bundled OpenWeatherMap fixtures.
+///
+/// Returns the same data for every city. Forecast dates are shifted so that
+/// the first entry falls on the current day, so "today" and "tomorrow" always
+/// have data. Useful for local demos and tests without an API key.
+pub struct FixtureProvider;
+
+#[async_trait]
+impl WeatherProvider for FixtureProvider {
+    async fn forecast(&self, _city: &str, _lang: Languages) -> Result<WeatherResponse, ProviderError> {
+        let mut resp: WeatherResponse = serde_json::from_str(FORECAST)?;
+
+        let first = resp.list
+            .first()
+            .and_then(|f| NaiveDateTime::parse_from_str(&f.dt_txt, DT_FORMAT).ok())
+            .ok_or(ProviderError::NoData)?;
+        let shift = Utc::now().date_naive() - first.date();
+
+        for forecast in &mut resp.list {
+            if let Ok(dt) = NaiveDateTime::parse_from_str(&forecast.dt_txt, DT_FORMAT) {
+                forecast.dt_txt = (dt + shift).format(DT_FORMAT).to_string();
+            }
+        }
+        resp.city.sunrise += shift.num_seconds();
+        resp.city.sunset += shift.num_seconds();
+
+        Ok(resp)
+    }
+
+    async fn air_quality(&self, _city: &str) -> Result<AirQuality, ProviderError> {
+        let resp: AirPollutionResponse = serde_json::from_str(AIR_POLLUTION)?;
+        resp.into_air_quality().ok_or(ProviderError::NoData)
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, IntoUrl};
+use serde::de::DeserializeOwned;
+
+use crate::api::provider::ProviderError;
+use crate::logging::error_chain;
+
+/// Delay before the first retry, doubled after every attempt.
+const BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Upper bound for a single retry delay, including `Retry-After`.
+const MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// HTTP client shared by the weather providers.
+///
+/// Cheap to clone, all clones share one connection pool.
+#[derive(Clone)]
+pub struct HttpClient {
+    client: Client,
+    retries: u32,
+}
+
+impl HttpClient {
+    /// Creates the client.
+    ///
+    /// # Arguments
+    /// - `connect_timeout` - limit for establishing a connection
+    /// - `timeout` - limit for the whole request, including reading the body
+    /// - `retries` - how many times a failed request is repeated
+    pub fn new(connect_timeout: Duration, timeout: Duration, retries: u32) -> Result<Self, reqwest::Error> {
+        let client = Client::builder()
+            .connect_timeout(connect_timeout)
+            .timeout(timeout)
+            .build()?;
+
+        Ok(Self { client, retries })
+    }
+
+    /// Sends a GET request and decodes the JSON response.
+    ///
+    /// Error responses are classified by [`ProviderError::from_response`]. Outages
+    /// (see [`ProviderError::is_outage`]) are retried with exponential backoff,
+    /// `Retry-After` is honoured up to [`MAX_DELAY`].
+    pub async fn get_json<T: DeserializeOwned>(&self, url: impl IntoUrl) -> Result<T, ProviderError> {
+        let url = url.into_url()?;
+        let mut attempt = 0;
+
+        loop {
+            let (error, retry_after) = match self.client.get(url.clone()).send().await {
+                Ok(resp) if resp.status().is_success() => {
+                    let body = resp.bytes().await?;
+                    return Ok(serde_json::from_slice(&body)?);
+                }
+                Ok(resp) => {
+                    let status = resp.status();
+                    let retry_after = retry_after(&resp);
+                    let body = resp.bytes().await.unwrap_or_default();
+                    (ProviderError::from_response(status, &body), retry_after)
+                }
+                Err(e) => (ProviderError::from(e), None),
+            };
+
+            if attempt >= self.retries || !error.is_outage() {
+                return Err(error);
+            }
+
+            let delay = retry_after.unwrap_or(BASE_DELAY * 2u32.pow(attempt)).min(MAX_DELAY);
+            tracing::debug!(attempt = attempt + 1, ?delay, error = %error_chain(&error), "Retrying provider request");
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Parses the `Retry-After` header given in seconds.
+fn retry_after(resp: &reqwest::Response) -> Option<Duration> {
+    resp.headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
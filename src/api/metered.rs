@@ -0,0 +1,60 @@
+use std::time::Instant;
+
+use async_trait::async_trait;
+
+use crate::api::models::{AirQuality, WeatherResponse};
+use crate::api::provider::{ProviderError, WeatherProvider};
+use crate::api::Provider;
+use crate::enums::languages::Languages;
+use crate::metrics;
+
+/// Wraps a [`WeatherProvider`] and records latency and status of every request.
+pub struct MeteredProvider {
+    inner: Provider,
+}
+
+impl MeteredProvider {
+    pub fn new(inner: Provider) -> Self {
+        Self { inner }
+    }
+}
+
+/// Returns the status label of a provider result: `ok`, the HTTP status code, or the error kind.
+fn status<T>(result: &Result<T, ProviderError>) -> String {
+    match result {
+        Ok(_) => "ok".to_string(),
+        Err(ProviderError::Request(_)) => "network".to_string(),
+        Err(ProviderError::Timeout(_)) => "timeout".to_string(),
+        Err(ProviderError::InvalidApiKey) => "401".to_string(),
+        Err(ProviderError::QuotaExceeded) => "429".to_string(),
+        Err(ProviderError::Status { status, .. }) => status.as_u16().to_string(),
+        Err(ProviderError::Decode(_)) => "decode".to_string(),
+        Err(ProviderError::CityNotFound) => "city-not-found".to_string(),
+        Err(ProviderError::NoData) => "no-data".to_string(),
+        Err(ProviderError::Unavailable) => "unavailable".to_string(),
+    }
+}
+
+#[async_trait]
+impl WeatherProvider for MeteredProvider {
+    async fn forecast(&self, city: &str, lang: Languages) -> Result<WeatherResponse, ProviderError> {
+        let start = Instant::now();
+        let result = self.inner.forecast(city, lang).await;
+        metrics::record_provider_request("forecast", &status(&result), start.elapsed());
+        result
+    }
+
+    async fn air_quality(&self, city: &str) -> Result<AirQuality, ProviderError> {
+        let start = Instant::now();
+        let result = self.inner.air_quality(city).await;
+        metrics::record_provider_request("air_quality", &status(&result), start.elapsed());
+        result
+    }
+
+    async fn ping(&self) -> Result<(), ProviderError> {
+        let start = Instant::now();
+        let result = self.inner.ping().await;
+        metrics::record_provider_request("ping", &status(&result), start.elapsed());
+        result
+    }
+}
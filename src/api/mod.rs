@@ -1,6 +1,19 @@
-mod api;
+mod breaker;
+mod cache;
+mod fixture;
+mod http;
+mod metered;
 pub mod models;
+mod openmeteo;
+mod openweathermap;
+pub mod provider;
 mod service;
 
-pub use api::fetch_forecast;
-pub use service::{today_weather, tomorrow_weather};
+pub use cache::CachedProvider;
+pub use fixture::FixtureProvider;
+pub use http::HttpClient;
+pub use metered::MeteredProvider;
+pub use openmeteo::OpenMeteo;
+pub use openweathermap::OpenWeatherMap;
+pub use provider::Provider;
+pub use service::{current_weather, sun_times, today_weather, tomorrow_weather, SunTimes};
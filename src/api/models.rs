@@ -1,32 +1,134 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
+use crate::enums::air_quality::AirQualityIndex;
+use crate::enums::conditions::WeatherCondition;
+
+#[derive(Debug, Clone, Deserialize)]
 pub struct WeatherResponse {
     pub list: Vec<Forecast>,
+    pub city: City,
+    /// Fetch time of stale data served while the provider is unavailable, `None` for fresh data.
+    #[serde(skip)]
+    pub as_of: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct City {
+    /// Shift in seconds from UTC.
+    pub timezone: i32,
+    /// Sunrise time, unix UTC.
+    pub sunrise: i64,
+    /// Sunset time, unix UTC.
+    pub sunset: i64,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Forecast {
     pub dt_txt: String,
     pub main: Main,
     pub weather: Vec<Weather>,
     pub wind: Wind,
+    /// Probability of precipitation from 0.0 to 1.0.
+    #[serde(default)]
+    pub pop: f64,
+    /// UV index, if the provider supplies it.
+    #[serde(default)]
+    pub uv_index: Option<f64>,
+    /// Maximum UV index of the slot's local day, if the provider supplies it.
+    #[serde(default)]
+    pub uv_index_max: Option<f64>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Main {
     pub temp: f64,
     pub feels_like: f64,
     pub humidity: i64,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Weather {
-    pub description: String,
+    pub id: u16,
+    pub icon: String,
+}
+
+impl Weather {
+    /// Returns the condition group for the provider's condition code.
+    pub fn condition(&self) -> WeatherCondition {
+        WeatherCondition::from_code(self.id)
+    }
+
+    /// Returns `true` if the icon code is a night variant (e.g. `01n`).
+    pub fn is_night(&self) -> bool {
+        self.icon.ends_with('n')
+    }
 }
 
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Wind {
     pub speed: f64,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GeocodingEntry {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AirPollutionResponse {
+    pub list: Vec<AirPollution>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AirPollution {
+    pub main: AirPollutionMain,
+    pub components: Components,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AirPollutionMain {
+    /// Air quality index from 1 (good) to 5 (very poor).
+    pub aqi: u8,
+}
+
+/// Pollutant concentrations in μg/m³.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Components {
+    pub pm2_5: f64,
+    pub pm10: f64,
+    pub o3: f64,
+    pub no2: f64,
+}
+
+impl AirPollutionResponse {
+    /// Converts the first (current) entry into [`AirQuality`].
+    pub fn into_air_quality(self) -> Option<AirQuality> {
+        let current = self.list.into_iter().next()?;
+
+        Some(AirQuality {
+            index: AirQualityIndex::from_index(current.main.aqi)?,
+            pm2_5: current.components.pm2_5,
+            pm10: current.components.pm10,
+            o3: current.components.o3,
+            no2: current.components.no2,
+            as_of: None,
+        })
+    }
+}
+
+/// Provider-independent air quality data.
+///
+/// Concentrations are in μg/m³.
+#[derive(Debug, Clone)]
+pub struct AirQuality {
+    pub index: AirQualityIndex,
+    pub pm2_5: f64,
+    pub pm10: f64,
+    pub o3: f64,
+    pub no2: f64,
+    /// Fetch time of stale data served while the provider is unavailable, `None` for fresh data.
+    pub as_of: Option<DateTime<Utc>>,
+}
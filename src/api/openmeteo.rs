@@ -0,0 +1,222 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use reqwest::Url;
+use serde::Deserialize;
+
+use crate::api::http::HttpClient;
+use crate::api::models::{AirQuality, City, Forecast, Main, WeatherResponse, Weather, Wind};
+use crate::api::provider::{ProviderError, WeatherProvider};
+use crate::enums::air_quality::AirQualityIndex;
+use crate::enums::conditions::WeatherCondition;
+use crate::enums::languages::Languages;
+
+const GEOCODING_URL: &str = "https://geocoding-api.open-meteo.com/v1/search";
+const FORECAST_URL: &str = "https://api.open-meteo.com/v1/forecast";
+const AIR_QUALITY_URL: &str = "https://air-quality-api.open-meteo.com/v1/air-quality";
+
+/// Hours between forecast slots, matching the OpenWeatherMap 3-hour forecast.
+const SLOT_HOURS: i64 = 3;
+
+/// City resolved by [`WeatherProvider::ping`].
+const PING_CITY: &str = "London";
+
+/// [`WeatherProvider`] backed by the Open-Meteo API.
+///
+/// Needs no API key and additionally supplies the UV index.
+pub struct OpenMeteo {
+    http: HttpClient,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeocodingResponse {
+    #[serde(default)]
+    results: Vec<GeocodingResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeocodingResult {
+    latitude: f64,
+    longitude: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForecastResponse {
+    utc_offset_seconds: i32,
+    hourly: Hourly,
+    daily: Daily,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hourly {
+    time: Vec<i64>,
+    temperature_2m: Vec<Option<f64>>,
+    apparent_temperature: Vec<Option<f64>>,
+    relative_humidity_2m: Vec<Option<i64>>,
+    weather_code: Vec<Option<u8>>,
+    wind_speed_10m: Vec<Option<f64>>,
+    is_day: Vec<Option<u8>>,
+    precipitation_probability: Vec<Option<f64>>,
+    uv_index: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Daily {
+    /// Local midnight of each day, unix UTC.
+    time: Vec<i64>,
+    sunrise: Vec<i64>,
+    sunset: Vec<i64>,
+    uv_index_max: Vec<Option<f64>>,
+}
+
+impl Daily {
+    /// Returns the maximum UV index of the local day containing the unix time `t`.
+    fn uv_index_max(&self, t: i64, utc_offset: i32) -> Option<f64> {
+        let date = local_date(t, utc_offset)?;
+        let i = self.time.iter().position(|&day| local_date(day, utc_offset) == Some(date))?;
+        self.uv_index_max.get(i).copied().flatten()
+    }
+}
+
+/// Returns the date of the unix time `t` in a city shifted by `utc_offset` seconds from UTC.
+fn local_date(t: i64, utc_offset: i32) -> Option<NaiveDate> {
+    DateTime::from_timestamp(t + i64::from(utc_offset), 0).map(|time| time.date_naive())
+}
+
+#[derive(Debug, Deserialize)]
+struct AirQualityResponse {
+    current: CurrentAirQuality,
+}
+
+#[derive(Debug, Deserialize)]
+struct CurrentAirQuality {
+    european_aqi: f64,
+    pm2_5: f64,
+    pm10: f64,
+    ozone: f64,
+    nitrogen_dioxide: f64,
+}
+
+impl OpenMeteo {
+    pub fn new(http: HttpClient) -> Self {
+        Self { http }
+    }
+
+    /// Resolves the city name to coordinates using the geocoding API.
+    ///
+    /// `language` is the provider language code used to match localized city names.
+    async fn coordinates(&self, city: &str, language: &str) -> Result<(f64, f64), ProviderError> {
+        let url = Url::parse_with_params(GEOCODING_URL, &[
+            ("name", city),
+            ("count", "1"),
+            ("language", language),
+        ]).map_err(|_| ProviderError::CityNotFound)?;
+
+        let resp = self.http.get_json::<GeocodingResponse>(url).await?;
+
+        resp.results
+            .first()
+            .map(|r| (r.latitude, r.longitude))
+            .ok_or(ProviderError::CityNotFound)
+    }
+}
+
+impl Hourly {
+    /// Builds a 3-hour forecast slot from the hourly entry at `i`.
+    fn slot(&self, i: usize) -> Option<Forecast> {
+        let time = DateTime::from_timestamp(self.time[i], 0)?;
+        let code = WeatherCondition::code_from_wmo((*self.weather_code.get(i)?)?);
+        let condition = WeatherCondition::from_code(code);
+        let is_day = (*self.is_day.get(i)?)? == 1;
+
+        Some(Forecast {
+            dt_txt: time.format("%Y-%m-%d %H:%M:%S").to_string(),
+            main: Main {
+                temp: (*self.temperature_2m.get(i)?)?,
+                feels_like: (*self.apparent_temperature.get(i)?)?,
+                humidity: (*self.relative_humidity_2m.get(i)?)?,
+            },
+            weather: vec![Weather {
+                id: code,
+                icon: format!("{}{}", condition.icon(), if is_day { "d" } else { "n" }),
+            }],
+            wind: Wind {
+                speed: (*self.wind_speed_10m.get(i)?)?,
+            },
+            pop: self.precipitation_probability.get(i).copied().flatten().unwrap_or_default() / 100.0,
+            uv_index: self.uv_index.get(i).copied().flatten(),
+            uv_index_max: None,
+        })
+    }
+}
+
+#[async_trait]
+impl WeatherProvider for OpenMeteo {
+    async fn forecast(&self, city: &str, lang: Languages) -> Result<WeatherResponse, ProviderError> {
+        let (lat, lon) = self.coordinates(city, lang.provider_code()).await?;
+        let url = Url::parse_with_params(FORECAST_URL, &[
+            ("latitude", lat.to_string().as_str()),
+            ("longitude", lon.to_string().as_str()),
+            ("hourly", "temperature_2m,apparent_temperature,relative_humidity_2m,weather_code,wind_speed_10m,is_day,precipitation_probability,uv_index"),
+            ("daily", "sunrise,sunset,uv_index_max"),
+            ("wind_speed_unit", "ms"),
+            ("timezone", "auto"),
+            ("timeformat", "unixtime"),
+            ("forecast_days", "6"),
+        ]).map_err(|_| ProviderError::CityNotFound)?;
+
+        let resp = self.http.get_json::<ForecastResponse>(url).await?;
+
+        // Hourly data starts at local midnight, so slots that already ended are dropped
+        // and the first one is the slot of the current time, as with OpenWeatherMap
+        let now = Utc::now().timestamp();
+        let list = resp.hourly.time
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| (*t / 3600) % SLOT_HOURS == 0 && *t + SLOT_HOURS * 3600 > now)
+            .filter_map(|(i, t)| {
+                let mut slot = resp.hourly.slot(i)?;
+                slot.uv_index_max = resp.daily.uv_index_max(*t, resp.utc_offset_seconds);
+                Some(slot)
+            })
+            .collect::<Vec<_>>();
+
+        if list.is_empty() {
+            return Err(ProviderError::NoData);
+        }
+
+        Ok(WeatherResponse {
+            list,
+            city: City {
+                timezone: resp.utc_offset_seconds,
+                sunrise: resp.daily.sunrise.first().copied().unwrap_or_default(),
+                sunset: resp.daily.sunset.first().copied().unwrap_or_default(),
+            },
+            as_of: None,
+        })
+    }
+
+    async fn air_quality(&self, city: &str) -> Result<AirQuality, ProviderError> {
+        let (lat, lon) = self.coordinates(city, "en").await?;
+        let url = Url::parse_with_params(AIR_QUALITY_URL, &[
+            ("latitude", lat.to_string().as_str()),
+            ("longitude", lon.to_string().as_str()),
+            ("current", "european_aqi,pm2_5,pm10,ozone,nitrogen_dioxide"),
+        ]).map_err(|_| ProviderError::CityNotFound)?;
+
+        let resp = self.http.get_json::<AirQualityResponse>(url).await?;
+
+        let current = resp.current;
+        Ok(AirQuality {
+            index: AirQualityIndex::from_european_aqi(current.european_aqi),
+            pm2_5: current.pm2_5,
+            pm10: current.pm10,
+            o3: current.ozone,
+            no2: current.nitrogen_dioxide,
+            as_of: None,
+        })
+    }
+
+    async fn ping(&self) -> Result<(), ProviderError> {
+        self.coordinates(PING_CITY, "en").await.map(|_| ())
+    }
+}
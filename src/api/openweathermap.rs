@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use reqwest::Url;
+
+use crate::api::http::HttpClient;
+use crate::api::models::{AirPollutionResponse, AirQuality, GeocodingEntry, WeatherResponse};
+use crate::api::provider::{ProviderError, WeatherProvider};
+use crate::enums::languages::Languages;
+
+const GEOCODING_URL: &str = "https://api.openweathermap.org/geo/1.0/direct";
+const FORECAST_URL: &str = "https://api.openweathermap.org/data/2.5/forecast";
+const AIR_POLLUTION_URL: &str = "https://api.openweathermap.org/data/2.5/air_pollution";
+
+/// City resolved by [`WeatherProvider::ping`].
+const PING_CITY: &str = "London";
+
+/// [`WeatherProvider`] backed by the OpenWeatherMap API.
+pub struct OpenWeatherMap {
+    http: HttpClient,
+    api_key: String,
+}
+
+impl OpenWeatherMap {
+    pub fn new(http: HttpClient, api_key: String) -> Self {
+        Self { http, api_key }
+    }
+
+    /// Resolves the city name to coordinates using the geocoding API.
+    async fn coordinates(&self, city: &str) -> Result<(f64, f64), ProviderError> {
+        let url = Url::parse_with_params(GEOCODING_URL, &[
+            ("q", city),
+            ("limit", "1"),
+            ("appid", self.api_key.as_str()),
+        ]).map_err(|_| ProviderError::CityNotFound)?;
+
+        let entries = self.http.get_json::<Vec<GeocodingEntry>>(url).await?;
+
+        entries
+            .first()
+            .map(|e| (e.lat, e.lon))
+            .ok_or(ProviderError::CityNotFound)
+    }
+}
+
+#[async_trait]
+impl WeatherProvider for OpenWeatherMap {
+    async fn forecast(&self, city: &str, lang: Languages) -> Result<WeatherResponse, ProviderError> {
+        let url = Url::parse_with_params(FORECAST_URL, &[
+            ("q", city),
+            ("appid", self.api_key.as_str()),
+            ("units", "metric"),
+            ("lang", lang.provider_code()),
+        ]).map_err(|_| ProviderError::CityNotFound)?;
+
+        let resp = self.http.get_json::<WeatherResponse>(url).await?;
+
+        Ok(resp)
+    }
+
+    async fn air_quality(&self, city: &str) -> Result<AirQuality, ProviderError> {
+        let (lat, lon) = self.coordinates(city).await?;
+        let url = Url::parse_with_params(AIR_POLLUTION_URL, &[
+            ("lat", lat.to_string().as_str()),
+            ("lon", lon.to_string().as_str()),
+            ("appid", self.api_key.as_str()),
+        ]).map_err(|_| ProviderError::CityNotFound)?;
+
+        let resp = self.http.get_json::<AirPollutionResponse>(url).await?;
+
+        resp.into_air_quality().ok_or(ProviderError::NoData)
+    }
+
+    async fn ping(&self) -> Result<(), ProviderError> {
+        // Geocoding is the cheapest call and also validates the API key
+        self.coordinates(PING_CITY).await.map(|_| ())
+    }
+}
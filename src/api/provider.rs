@@ -0,0 +1,166 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::api::models::{AirQuality, WeatherResponse};
+use crate::enums::languages::Languages;
+
+/// Shared weather provider injected into handlers.
+pub type Provider = Arc<dyn WeatherProvider>;
+
+/// A source of weather data.
+///
+/// Implemented for:
+/// - [`OpenWeatherMap`](crate::api::OpenWeatherMap) (live API)
+/// - [`OpenMeteo`](crate::api::OpenMeteo) (live API with UV index, no key)
+/// - [`FixtureProvider`](crate::api::FixtureProvider) (bundled JSON, no network)
+/// - [`CachedProvider`](crate::api::CachedProvider) (in-memory cache around another provider)
+/// - [`MeteredProvider`](crate::api::MeteredProvider) (request metrics around another provider)
+#[async_trait]
+pub trait WeatherProvider: Send + Sync {
+    /// Returns the 5 day / 3 hour forecast for the city.
+    async fn forecast(&self, city: &str, lang: Languages) -> Result<WeatherResponse, ProviderError>;
+
+    /// Returns the current air quality index and pollutant concentrations for the city.
+    async fn air_quality(&self, city: &str) -> Result<AirQuality, ProviderError>;
+
+    /// Checks that the provider is reachable, used by the readiness probe.
+    ///
+    /// Providers without a remote API are always reachable.
+    async fn ping(&self) -> Result<(), ProviderError> {
+        Ok(())
+    }
+}
+
+/// Errors returned by a [`WeatherProvider`].
+#[derive(Debug)]
+pub enum ProviderError {
+    /// HTTP request failed, e.g. the connection could not be established.
+    Request(reqwest::Error),
+
+    /// Provider did not respond in time.
+    Timeout(reqwest::Error),
+
+    /// Provider rejected the API key (`401`).
+    InvalidApiKey,
+
+    /// Request quota or rate limit of the API key is exhausted (`429`).
+    QuotaExceeded,
+
+    /// Provider returned another error status.
+    Status {
+        status: StatusCode,
+        /// Error message from the response body, if any.
+        message: Option<String>,
+    },
+
+    /// Response body could not be decoded.
+    Decode(serde_json::Error),
+
+    /// City could not be resolved by the provider.
+    CityNotFound,
+
+    /// Provider returned no data for the request.
+    NoData,
+
+    /// Provider is down and there is no cached data to fall back to.
+    Unavailable,
+}
+
+impl fmt::Display for ProviderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProviderError::Request(_) => write!(f, "request failed"),
+            ProviderError::Timeout(_) => write!(f, "request timed out"),
+            ProviderError::InvalidApiKey => write!(f, "invalid API key"),
+            ProviderError::QuotaExceeded => write!(f, "quota exceeded"),
+            ProviderError::Status { status, message: Some(message) } => write!(f, "status {status}: {message}"),
+            ProviderError::Status { status, message: None } => write!(f, "status {status}"),
+            ProviderError::Decode(_) => write!(f, "invalid response"),
+            ProviderError::CityNotFound => write!(f, "city not found"),
+            ProviderError::NoData => write!(f, "no data"),
+            ProviderError::Unavailable => write!(f, "provider unavailable"),
+        }
+    }
+}
+
+impl Error for ProviderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ProviderError::Request(e) | ProviderError::Timeout(e) => Some(e),
+            ProviderError::Decode(e) => Some(e),
+            ProviderError::InvalidApiKey
+            | ProviderError::QuotaExceeded
+            | ProviderError::Status { .. }
+            | ProviderError::CityNotFound
+            | ProviderError::NoData
+            | ProviderError::Unavailable => None,
+        }
+    }
+}
+
+/// Error body of the providers: `{"cod": 404, "message": "..."}` (OpenWeatherMap)
+/// or `{"error": true, "reason": "..."}` (Open-Meteo).
+#[derive(Deserialize)]
+struct ErrorBody {
+    #[serde(alias = "reason")]
+    message: Option<String>,
+}
+
+impl ProviderError {
+    /// Classifies an error response by its status and body.
+    ///
+    /// # Arguments
+    /// - `status` - HTTP status of the response
+    /// - `body` - response body, parsed for the provider's error message
+    pub fn from_response(status: StatusCode, body: &[u8]) -> Self {
+        let message = serde_json::from_slice::<ErrorBody>(body)
+            .ok()
+            .and_then(|b| b.message);
+
+        match status {
+            StatusCode::UNAUTHORIZED => ProviderError::InvalidApiKey,
+            StatusCode::TOO_MANY_REQUESTS => ProviderError::QuotaExceeded,
+            StatusCode::NOT_FOUND if message.as_deref().is_some_and(|m| m.eq_ignore_ascii_case("city not found")) => {
+                ProviderError::CityNotFound
+            }
+            _ => ProviderError::Status { status, message },
+        }
+    }
+
+    /// Returns `true` if the error means the provider itself is failing
+    /// (network errors, timeouts, `5xx` and `429` responses) rather than the request being wrong.
+    pub fn is_outage(&self) -> bool {
+        match self {
+            ProviderError::Request(_)
+            | ProviderError::Timeout(_)
+            | ProviderError::QuotaExceeded
+            | ProviderError::Unavailable => true,
+            ProviderError::Status { status, .. } => status.is_server_error(),
+            ProviderError::InvalidApiKey
+            | ProviderError::Decode(_)
+            | ProviderError::CityNotFound
+            | ProviderError::NoData => false,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ProviderError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            ProviderError::Timeout(e)
+        } else {
+            ProviderError::Request(e)
+        }
+    }
+}
+
+impl From<serde_json::Error> for ProviderError {
+    fn from(e: serde_json::Error) -> Self {
+        ProviderError::Decode(e)
+    }
+}
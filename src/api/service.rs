@@ -1,6 +1,13 @@
-use chrono::{NaiveDate, Utc, Duration};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveTime, Utc};
 use crate::api::models::{Forecast, WeatherResponse};
 
+/// Sunrise and sunset of the current day in the city's local time.
+pub struct SunTimes {
+    pub sunrise: NaiveTime,
+    pub sunset: NaiveTime,
+    pub day_length: Duration,
+}
+
 fn get_weather_for_date(resp: &WeatherResponse, date: NaiveDate) -> Option<&Forecast> {
     let date_str = date.format("%Y-%m-%d").to_string();
 
@@ -17,6 +24,10 @@ fn get_weather_for_date(resp: &WeatherResponse, date: NaiveDate) -> Option<&Fore
         })
 }
 
+pub fn current_weather(response: &WeatherResponse) -> Option<&Forecast> {
+    response.list.first()
+}
+
 pub fn today_weather(response: &WeatherResponse) -> Option<&Forecast> {
     let today = Utc::now().date_naive();
     get_weather_for_date(response, today)
@@ -25,4 +36,24 @@ pub fn today_weather(response: &WeatherResponse) -> Option<&Forecast> {
 pub fn tomorrow_weather(response: &WeatherResponse) -> Option<&Forecast> {
     let tomorrow = Utc::now().date_naive() + Duration::days(1);
     get_weather_for_date(response, tomorrow)
-}
\ No newline at end of file
+}
+
+/// Returns sunrise, sunset and day length from the response city metadata.
+///
+/// Returns `None` if the provider has no sun data for the city (e.g. polar day or night).
+pub fn sun_times(response: &WeatherResponse) -> Option<SunTimes> {
+    let city = &response.city;
+    if city.sunrise == 0 || city.sunset == 0 {
+        return None;
+    }
+
+    let offset = FixedOffset::east_opt(city.timezone)?;
+    let sunrise = DateTime::from_timestamp(city.sunrise, 0)?.with_timezone(&offset);
+    let sunset = DateTime::from_timestamp(city.sunset, 0)?.with_timezone(&offset);
+
+    Some(SunTimes {
+        sunrise: sunrise.time(),
+        sunset: sunset.time(),
+        day_length: sunset - sunrise,
+    })
+}
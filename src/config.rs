@@ -0,0 +1,511 @@
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use dotenvy::dotenv;
+use reqwest::Url;
+use serde::Deserialize;
+
+use crate::db::pool::Backend;
+use crate::enums::languages::Languages;
+use crate::logging::LogFormat;
+use crate::webhook::WebhookConfig;
+
+#[cfg(test)]
+mod tests;
+
+/// Config file read when `CONFIG_FILE` is not set. Optional.
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+const DEFAULT_POOL_SIZE: usize = 10;
+const DEFAULT_FORECAST_CACHE_TTL: u64 = 600;
+const DEFAULT_AIR_QUALITY_CACHE_TTL: u64 = 1800;
+const DEFAULT_PROVIDER_CONNECT_TIMEOUT: u64 = 5;
+const DEFAULT_PROVIDER_TIMEOUT: u64 = 10;
+const DEFAULT_PROVIDER_RETRIES: u32 = 2;
+const DEFAULT_DELIVERY_RATE: u32 = 25;
+const DEFAULT_THROTTLE_LIMIT: usize = 5;
+const DEFAULT_INLINE_THROTTLE_LIMIT: usize = 20;
+const DEFAULT_THROTTLE_WINDOW: u64 = 10;
+const DEFAULT_WEBHOOK_ADDR: &str = "127.0.0.1:8443";
+
+/// Weather data source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderKind {
+    OpenWeatherMap,
+    OpenMeteo,
+    Fixture,
+}
+
+impl ProviderKind {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "openweathermap" => Some(ProviderKind::OpenWeatherMap),
+            "openmeteo" => Some(ProviderKind::OpenMeteo),
+            "fixture" => Some(ProviderKind::Fixture),
+            &_ => None,
+        }
+    }
+}
+
+/// Application configuration, validated at startup.
+///
+/// Values are taken, in order of priority, from:
+/// 1. Environment variables (including `.env`).
+/// 2. The TOML file from `CONFIG_FILE` (default `config.toml`, optional).
+/// 3. Built-in defaults.
+///
+/// TOML keys are the lowercase names of the environment variables
+/// (e.g. `DB_POOL_SIZE` -> `db_pool_size`).
+pub struct Config {
+    /// Telegram bot token (`TELEGRAM_TOKEN`).
+    pub telegram_token: String,
+    /// Custom Bot API server (`TELEGRAM_API_URL`).
+    pub telegram_api_url: Option<Url>,
+    /// PostgreSQL or SQLite connection string (`DATABASE_URL`), settings are kept in memory if unset.
+    pub database_url: Option<String>,
+    /// Maximum number of pooled database connections (`DB_POOL_SIZE`).
+    pub db_pool_size: usize,
+    /// Weather data source (`WEATHER_PROVIDER`).
+    pub provider: ProviderKind,
+    /// OpenWeatherMap API key (`WEATHER_API_KEY`), required for that provider.
+    pub weather_api_key: Option<String>,
+    /// How long forecasts are cached (`FORECAST_CACHE_TTL`, seconds).
+    pub forecast_cache_ttl: Duration,
+    /// How long air quality data is cached (`AIR_QUALITY_CACHE_TTL`, seconds).
+    pub air_quality_cache_ttl: Duration,
+    /// Limit for connecting to the weather provider (`PROVIDER_CONNECT_TIMEOUT`, seconds).
+    pub provider_connect_timeout: Duration,
+    /// Limit for a whole weather provider request (`PROVIDER_TIMEOUT`, seconds).
+    pub provider_timeout: Duration,
+    /// Retries of a failed weather provider request (`PROVIDER_RETRIES`).
+    pub provider_retries: u32,
+    /// Telegram user IDs with access to admin commands (`ADMIN_IDS`, comma-separated).
+    pub admins: Vec<i64>,
+    /// Languages for unsupported Telegram language codes (`LANGUAGE_FALLBACKS`, e.g. `ru=uk,be=uk`).
+    pub language_fallbacks: HashMap<String, Languages>,
+    /// Webhook settings if `BOT_MODE=webhook`, otherwise long polling is used.
+    pub webhook: Option<WebhookConfig>,
+    /// Address of the `/healthz` and `/readyz` probe server (`HEALTH_ADDR`), disabled if unset.
+    pub health_addr: Option<SocketAddr>,
+    /// Address of the Prometheus `/metrics` endpoint (`METRICS_ADDR`), disabled if unset.
+    pub metrics_addr: Option<SocketAddr>,
+    /// Global outbound message rate for broadcasts (`DELIVERY_RATE`, messages per second).
+    pub delivery_rate: u32,
+    /// Weather requests a user may make per window (`THROTTLE_LIMIT`).
+    pub throttle_limit: usize,
+    /// Inline queries a user may make per window (`INLINE_THROTTLE_LIMIT`).
+    pub inline_throttle_limit: usize,
+    /// Length of the throttling window (`THROTTLE_WINDOW`, seconds).
+    pub throttle_window: Duration,
+    /// Log output format (`LOG_FORMAT`, `text` or `json`).
+    pub log_format: LogFormat,
+}
+
+/// Placeholder printed instead of secrets.
+pub(crate) const HIDDEN: &str = "<hidden>";
+
+// Written by hand so tokens and passwords never end up in logs
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Config")
+            .field("telegram_token", &HIDDEN)
+            .field("telegram_api_url", &self.telegram_api_url)
+            .field("database_url", &self.database_url.as_ref().map(|url| database_scheme(url)))
+            .field("db_pool_size", &self.db_pool_size)
+            .field("provider", &self.provider)
+            .field("weather_api_key", &self.weather_api_key.as_ref().map(|_| HIDDEN))
+            .field("forecast_cache_ttl", &self.forecast_cache_ttl)
+            .field("air_quality_cache_ttl", &self.air_quality_cache_ttl)
+            .field("provider_connect_timeout", &self.provider_connect_timeout)
+            .field("provider_timeout", &self.provider_timeout)
+            .field("provider_retries", &self.provider_retries)
+            .field("admins", &self.admins)
+            .field("language_fallbacks", &self.language_fallbacks)
+            .field("webhook", &self.webhook)
+            .field("health_addr", &self.health_addr)
+            .field("metrics_addr", &self.metrics_addr)
+            .field("delivery_rate", &self.delivery_rate)
+            .field("throttle_limit", &self.throttle_limit)
+            .field("inline_throttle_limit", &self.inline_throttle_limit)
+            .field("throttle_window", &self.throttle_window)
+            .field("log_format", &self.log_format)
+            .finish()
+    }
+}
+
+/// Errors that can occur while loading the configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// Required setting is not set.
+    Missing(&'static str),
+
+    /// Setting has a value that cannot be used.
+    Invalid {
+        key: &'static str,
+        value: String,
+        reason: &'static str,
+    },
+
+    /// Config file exists but cannot be read or parsed.
+    File {
+        path: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Missing(key) => write!(f, "{key} is not set (environment, .env or config file)"),
+            ConfigError::Invalid { key, value, reason } => write!(f, "{key}={value:?} is invalid: {reason}"),
+            ConfigError::File { path, reason } => write!(f, "cannot load config file {path}: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Raw values from the TOML config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileConfig {
+    telegram_token: Option<String>,
+    telegram_api_url: Option<String>,
+    database_url: Option<String>,
+    db_pool_size: Option<usize>,
+    weather_provider: Option<String>,
+    weather_api_key: Option<String>,
+    forecast_cache_ttl: Option<u64>,
+    air_quality_cache_ttl: Option<u64>,
+    provider_connect_timeout: Option<u64>,
+    provider_timeout: Option<u64>,
+    provider_retries: Option<u32>,
+    admin_ids: Option<Vec<i64>>,
+    language_fallbacks: Option<HashMap<String, String>>,
+    bot_mode: Option<String>,
+    webhook_url: Option<String>,
+    webhook_secret: Option<String>,
+    webhook_addr: Option<String>,
+    webhook_register: Option<bool>,
+    health_addr: Option<String>,
+    metrics_addr: Option<String>,
+    log_format: Option<String>,
+    delivery_rate: Option<u32>,
+    throttle_limit: Option<usize>,
+    inline_throttle_limit: Option<usize>,
+    throttle_window: Option<u64>,
+}
+
+/// Looks up settings in the environment, falling back to the config file.
+struct Source {
+    env: HashMap<String, String>,
+}
+
+impl Source {
+    /// Takes a snapshot of the process environment.
+    fn from_env() -> Self {
+        let env = env::vars_os()
+            .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+            .collect();
+
+        Self { env }
+    }
+
+    fn get(&self, key: &'static str, file_value: Option<String>) -> Option<String> {
+        // Empty values count as unset, so blank `.env` entries don't hide the file
+        self.env
+            .get(key)
+            .cloned()
+            .filter(|v| !v.trim().is_empty())
+            .or(file_value.filter(|v| !v.trim().is_empty()))
+    }
+
+    fn require(&self, key: &'static str, file_value: Option<String>) -> Result<String, ConfigError> {
+        self.get(key, file_value).ok_or(ConfigError::Missing(key))
+    }
+
+    fn parse<T: std::str::FromStr>(
+        &self,
+        key: &'static str,
+        file_value: Option<String>,
+        reason: &'static str,
+    ) -> Result<Option<T>, ConfigError> {
+        self.get(key, file_value)
+            .map(|value| value.trim().parse().map_err(|_| ConfigError::Invalid { key, value, reason }))
+            .transpose()
+    }
+}
+
+impl Config {
+    /// Loads and validates the configuration.
+    ///
+    /// Returns a [`ConfigError`] describing the first invalid or missing setting.
+    pub fn load() -> Result<Self, ConfigError> {
+        dotenv().ok();
+
+        let path = env::var("CONFIG_FILE").ok();
+        let file = match fs::read_to_string(path.as_deref().unwrap_or(DEFAULT_CONFIG_FILE)) {
+            Ok(content) => toml::from_str(&content).map_err(|e| ConfigError::File {
+                path: path.clone().unwrap_or(DEFAULT_CONFIG_FILE.to_string()),
+                reason: e.to_string(),
+            })?,
+            // Only the default file is optional
+            Err(_) if path.is_none() => FileConfig::default(),
+            Err(e) => return Err(ConfigError::File { path: path.unwrap_or_default(), reason: e.to_string() }),
+        };
+
+        Self::from_sources(&Source::from_env(), file)
+    }
+
+    /// Builds the configuration from environment variables and file values.
+    fn from_sources(src: &Source, file: FileConfig) -> Result<Self, ConfigError> {
+        let telegram_token = src.require("TELEGRAM_TOKEN", file.telegram_token)?;
+        let telegram_api_url = src.parse("TELEGRAM_API_URL", file.telegram_api_url, "expected a URL")?;
+        let database_url = src.get("DATABASE_URL", file.database_url);
+        if let Some(url) = &database_url
+            && Backend::from_url(url).is_none()
+        {
+            return Err(ConfigError::Invalid {
+                key: "DATABASE_URL",
+                value: database_scheme(url).to_string(),
+                reason: "expected a postgres:// or sqlite:// URL",
+            });
+        }
+
+        let db_pool_size = src
+            .parse("DB_POOL_SIZE", file.db_pool_size.map(|v| v.to_string()), "expected a positive number")?
+            .unwrap_or(DEFAULT_POOL_SIZE);
+        if db_pool_size == 0 {
+            return Err(ConfigError::Invalid {
+                key: "DB_POOL_SIZE",
+                value: db_pool_size.to_string(),
+                reason: "expected a positive number",
+            });
+        }
+
+        let provider = match src.get("WEATHER_PROVIDER", file.weather_provider) {
+            Some(value) => ProviderKind::from_str(value.trim()).ok_or(ConfigError::Invalid {
+                key: "WEATHER_PROVIDER",
+                value,
+                reason: "expected openweathermap, openmeteo or fixture",
+            })?,
+            None => ProviderKind::OpenWeatherMap,
+        };
+
+        let weather_api_key = src.get("WEATHER_API_KEY", file.weather_api_key);
+        if provider == ProviderKind::OpenWeatherMap && weather_api_key.is_none() {
+            return Err(ConfigError::Missing("WEATHER_API_KEY"));
+        }
+
+        let forecast_cache_ttl = src
+            .parse("FORECAST_CACHE_TTL", file.forecast_cache_ttl.map(|v| v.to_string()), "expected seconds")?
+            .unwrap_or(DEFAULT_FORECAST_CACHE_TTL);
+        let air_quality_cache_ttl = src
+            .parse("AIR_QUALITY_CACHE_TTL", file.air_quality_cache_ttl.map(|v| v.to_string()), "expected seconds")?
+            .unwrap_or(DEFAULT_AIR_QUALITY_CACHE_TTL);
+
+        let provider_connect_timeout = src
+            .parse("PROVIDER_CONNECT_TIMEOUT", file.provider_connect_timeout.map(|v| v.to_string()), "expected seconds")?
+            .unwrap_or(DEFAULT_PROVIDER_CONNECT_TIMEOUT);
+        let provider_timeout = src
+            .parse("PROVIDER_TIMEOUT", file.provider_timeout.map(|v| v.to_string()), "expected seconds")?
+            .unwrap_or(DEFAULT_PROVIDER_TIMEOUT);
+        let provider_retries = src
+            .parse("PROVIDER_RETRIES", file.provider_retries.map(|v| v.to_string()), "expected a number")?
+            .unwrap_or(DEFAULT_PROVIDER_RETRIES);
+        if provider_connect_timeout == 0 {
+            return Err(ConfigError::Invalid {
+                key: "PROVIDER_CONNECT_TIMEOUT",
+                value: provider_connect_timeout.to_string(),
+                reason: "expected seconds",
+            });
+        }
+        if provider_timeout == 0 {
+            return Err(ConfigError::Invalid {
+                key: "PROVIDER_TIMEOUT",
+                value: provider_timeout.to_string(),
+                reason: "expected seconds",
+            });
+        }
+
+        let admins = match src.get("ADMIN_IDS", file.admin_ids.map(|ids| {
+            ids.iter().map(i64::to_string).collect::<Vec<_>>().join(",")
+        })) {
+            Some(value) => parse_admins(&value)?,
+            None => Vec::new(),
+        };
+
+        let language_fallbacks = match src.get("LANGUAGE_FALLBACKS", file.language_fallbacks.map(|fallbacks| {
+            fallbacks.iter().map(|(code, lang)| format!("{code}={lang}")).collect::<Vec<_>>().join(",")
+        })) {
+            Some(value) => parse_language_fallbacks(&value)?,
+            None => HashMap::new(),
+        };
+
+        let webhook = match src.get("BOT_MODE", file.bot_mode).as_deref().map(str::trim) {
+            None | Some("polling") => None,
+            Some("webhook") => {
+                let url = src
+                    .parse("WEBHOOK_URL", file.webhook_url, "expected a URL")?
+                    .ok_or(ConfigError::Missing("WEBHOOK_URL"))?;
+                let secret = src.require("WEBHOOK_SECRET", file.webhook_secret)?;
+                if !is_valid_secret(&secret) {
+                    return Err(ConfigError::Invalid {
+                        key: "WEBHOOK_SECRET",
+                        value: HIDDEN.to_string(),
+                        reason: "expected 1-256 characters A-Z, a-z, 0-9, _ or -",
+                    });
+                }
+                let address: SocketAddr = src
+                    .parse("WEBHOOK_ADDR", file.webhook_addr, "expected a socket address like 127.0.0.1:8443")?
+                    .unwrap_or_else(|| DEFAULT_WEBHOOK_ADDR.parse().expect("valid default address"));
+                let register = src
+                    .parse("WEBHOOK_REGISTER", file.webhook_register.map(|v| v.to_string()), "expected true or false")?
+                    .unwrap_or(true);
+
+                Some(WebhookConfig { address, url, secret, register })
+            }
+            Some(value) => {
+                return Err(ConfigError::Invalid {
+                    key: "BOT_MODE",
+                    value: value.to_string(),
+                    reason: "expected polling or webhook",
+                });
+            }
+        };
+
+        let health_addr = src.parse("HEALTH_ADDR", file.health_addr, "expected a socket address like 0.0.0.0:8080")?;
+        let metrics_addr = src.parse("METRICS_ADDR", file.metrics_addr, "expected a socket address like 0.0.0.0:9100")?;
+
+        let delivery_rate = src
+            .parse("DELIVERY_RATE", file.delivery_rate.map(|v| v.to_string()), "expected messages per second, 1-30")?
+            .unwrap_or(DEFAULT_DELIVERY_RATE);
+        if !(1..=30).contains(&delivery_rate) {
+            return Err(ConfigError::Invalid {
+                key: "DELIVERY_RATE",
+                value: delivery_rate.to_string(),
+                reason: "expected messages per second, 1-30",
+            });
+        }
+
+        let throttle_limit = src
+            .parse("THROTTLE_LIMIT", file.throttle_limit.map(|v| v.to_string()), "expected a positive number")?
+            .unwrap_or(DEFAULT_THROTTLE_LIMIT);
+        let inline_throttle_limit = src
+            .parse("INLINE_THROTTLE_LIMIT", file.inline_throttle_limit.map(|v| v.to_string()), "expected a positive number")?
+            .unwrap_or(DEFAULT_INLINE_THROTTLE_LIMIT);
+        let throttle_window = src
+            .parse("THROTTLE_WINDOW", file.throttle_window.map(|v| v.to_string()), "expected seconds")?
+            .unwrap_or(DEFAULT_THROTTLE_WINDOW);
+        if throttle_limit == 0 {
+            return Err(ConfigError::Invalid {
+                key: "THROTTLE_LIMIT",
+                value: throttle_limit.to_string(),
+                reason: "expected a positive number",
+            });
+        }
+        if inline_throttle_limit == 0 {
+            return Err(ConfigError::Invalid {
+                key: "INLINE_THROTTLE_LIMIT",
+                value: inline_throttle_limit.to_string(),
+                reason: "expected a positive number",
+            });
+        }
+        if throttle_window == 0 {
+            return Err(ConfigError::Invalid {
+                key: "THROTTLE_WINDOW",
+                value: throttle_window.to_string(),
+                reason: "expected seconds",
+            });
+        }
+
+        let log_format = match src.get("LOG_FORMAT", file.log_format) {
+            Some(value) => LogFormat::from_str(value.trim()).ok_or(ConfigError::Invalid {
+                key: "LOG_FORMAT",
+                value,
+                reason: "expected text or json",
+            })?,
+            None => LogFormat::default(),
+        };
+
+        Ok(Self {
+            telegram_token,
+            telegram_api_url,
+            database_url,
+            db_pool_size,
+            provider,
+            weather_api_key,
+            forecast_cache_ttl: Duration::from_secs(forecast_cache_ttl),
+            air_quality_cache_ttl: Duration::from_secs(air_quality_cache_ttl),
+            provider_connect_timeout: Duration::from_secs(provider_connect_timeout),
+            provider_timeout: Duration::from_secs(provider_timeout),
+            provider_retries,
+            admins,
+            language_fallbacks,
+            webhook,
+            health_addr,
+            metrics_addr,
+            delivery_rate,
+            throttle_limit,
+            inline_throttle_limit,
+            throttle_window: Duration::from_secs(throttle_window),
+            log_format,
+        })
+    }
+
+    /// Returns `true` if the user may use admin commands.
+    pub fn is_admin(&self, user_id: i64) -> bool {
+        self.admins.contains(&user_id)
+    }
+}
+
+/// Parses a comma-separated list of Telegram user IDs.
+fn parse_admins(value: &str) -> Result<Vec<i64>, ConfigError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| match id.parse::<i64>() {
+            Ok(id) if id > 0 => Ok(id),
+            _ => Err(ConfigError::Invalid {
+                key: "ADMIN_IDS",
+                value: value.to_string(),
+                reason: "expected comma-separated positive Telegram user IDs",
+            }),
+        })
+        .collect()
+}
+
+/// Parses comma-separated `code=language` pairs, e.g. `ru=uk,be=uk`.
+fn parse_language_fallbacks(value: &str) -> Result<HashMap<String, Languages>, ConfigError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            pair.split_once('=')
+                .and_then(|(code, lang)| Some((code.trim().to_lowercase(), Languages::from_str(lang.trim())?)))
+                .filter(|(code, _)| !code.is_empty())
+                .ok_or_else(|| ConfigError::Invalid {
+                    key: "LANGUAGE_FALLBACKS",
+                    value: value.to_string(),
+                    reason: "expected comma-separated code=language pairs with languages from locales/",
+                })
+        })
+        .collect()
+}
+
+/// Returns only the scheme of a database URL, the rest may contain a password.
+fn database_scheme(url: &str) -> &str {
+    url.split(':').next().unwrap_or_default()
+}
+
+/// Checks the secret token against Telegram's allowed format.
+fn is_valid_secret(secret: &str) -> bool {
+    (1..=256).contains(&secret.len())
+        && secret.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
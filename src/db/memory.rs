@@ -0,0 +1,161 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate, Utc};
+
+use crate::enums::units::{SpeedUnits, TemperatureUnits};
+use super::models::{User, UserStats};
+use super::repository::UserRepository;
+
+/// User repository kept in process memory.
+///
+/// Used when no database is configured (local demos) and in tests.
+/// Everything is lost when the bot stops.
+#[derive(Default)]
+pub struct MemoryUserRepository {
+    users: Mutex<BTreeMap<i64, User>>,
+}
+
+impl MemoryUserRepository {
+    /// Applies `update` to the stored user, does nothing for unknown users.
+    fn update(&self, user_id: i64, update: impl FnOnce(&mut User)) {
+        if let Some(user) = self.users.lock().unwrap().get_mut(&user_id) {
+            update(user);
+        }
+    }
+}
+
+#[async_trait]
+impl UserRepository for MemoryUserRepository {
+    async fn get_user(&self, user_id: i64) -> Option<User> {
+        self.users.lock().unwrap().get(&user_id).cloned()
+    }
+
+    async fn upsert_city(&self, user_id: i64, city: &str, language: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let now = Utc::now().naive_utc();
+
+        self.users.lock().unwrap()
+            .entry(user_id)
+            .and_modify(|user| {
+                user.city = city.to_string();
+                user.updated_at = now;
+            })
+            .or_insert_with(|| User {
+                id: user_id,
+                city: city.to_string(),
+                language: language.to_string(),
+                temperature_unit: TemperatureUnits::default().as_str().to_string(),
+                speed_unit: SpeedUnits::default().as_str().to_string(),
+                created_at: now,
+                updated_at: now,
+                last_active_at: now,
+                active: true,
+                uv_alerts: false,
+                uv_alert_sent_on: None,
+            });
+
+        Ok(())
+    }
+
+    async fn set_lang(&self, user_id: i64, lang: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.update(user_id, |user| {
+            user.language = lang.to_string();
+            user.updated_at = Utc::now().naive_utc();
+        });
+        Ok(())
+    }
+
+    async fn set_temp_unit(&self, user_id: i64, temp: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.update(user_id, |user| {
+            user.temperature_unit = temp.to_string();
+            user.updated_at = Utc::now().naive_utc();
+        });
+        Ok(())
+    }
+
+    async fn set_speed_unit(&self, user_id: i64, speed: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.update(user_id, |user| {
+            user.speed_unit = speed.to_string();
+            user.updated_at = Utc::now().naive_utc();
+        });
+        Ok(())
+    }
+
+    async fn set_uv_alerts(&self, user_id: i64, enabled: bool) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.update(user_id, |user| {
+            user.uv_alerts = enabled;
+            user.updated_at = Utc::now().naive_utc();
+        });
+        Ok(())
+    }
+
+    async fn touch(&self, user_id: i64) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.update(user_id, |user| {
+            user.last_active_at = Utc::now().naive_utc();
+            user.active = true;
+        });
+        Ok(())
+    }
+
+    async fn stats(&self, active_days: i64) -> Result<UserStats, Box<dyn Error + Send + Sync>> {
+        let users = self.users.lock().unwrap();
+        let active_since = Utc::now().naive_utc() - Duration::days(active_days);
+
+        // `(value, count)` pairs, most used first
+        let count_by = |field: fn(&User) -> &str| {
+            let mut counts = HashMap::<String, i64>::new();
+            for user in users.values() {
+                *counts.entry(field(user).to_string()).or_default() += 1;
+            }
+
+            let mut counts = counts.into_iter().collect::<Vec<_>>();
+            counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            counts
+        };
+
+        Ok(UserStats {
+            users: users.keys().filter(|&&id| id > 0).count() as i64,
+            groups: users.keys().filter(|&&id| id < 0).count() as i64,
+            active: users.values().filter(|u| u.id > 0 && u.last_active_at >= active_since).count() as i64,
+            inactive: users.values().filter(|u| !u.active).count() as i64,
+            languages: count_by(|u| &u.language),
+            temperature_units: count_by(|u| &u.temperature_unit),
+            speed_units: count_by(|u| &u.speed_unit),
+        })
+    }
+
+    async fn chat_ids(&self) -> Result<Vec<i64>, Box<dyn Error + Send + Sync>> {
+        let users = self.users.lock().unwrap();
+        Ok(users.values().filter(|u| u.active).map(|u| u.id).collect())
+    }
+
+    async fn uv_alert_chats(&self) -> Result<Vec<User>, Box<dyn Error + Send + Sync>> {
+        let users = self.users.lock().unwrap();
+        Ok(users.values().filter(|u| u.active && u.uv_alerts).cloned().collect())
+    }
+
+    async fn claim_uv_alert(&self, chat_id: i64, date: NaiveDate) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        let mut users = self.users.lock().unwrap();
+        let Some(user) = users.get_mut(&chat_id).filter(|u| u.uv_alert_sent_on.is_none_or(|sent| sent < date)) else {
+            return Ok(false);
+        };
+
+        user.uv_alert_sent_on = Some(date);
+        Ok(true)
+    }
+
+    async fn set_active(&self, chat_id: i64, active: bool) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.update(chat_id, |user| user.active = active);
+        Ok(())
+    }
+
+    async fn delete_user(&self, user_id: i64) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        Ok(self.users.lock().unwrap().remove(&user_id).is_some())
+    }
+
+    async fn ping(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(())
+    }
+}
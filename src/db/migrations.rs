@@ -0,0 +1,108 @@
+use std::error::Error;
+
+use diesel::query_builder::SqlQuery;
+use diesel::sql_types::Integer;
+use diesel::{sql_query, QueryableByName};
+use diesel_async::methods::{ExecuteDsl, LoadQuery};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+
+/// Schema changes in order, each applied once and recorded in `schema_migrations`.
+///
+/// Statements must be valid for both PostgreSQL and SQLite.
+/// Never edit an applied migration, append a new one instead.
+const MIGRATIONS: &[(i32, &str)] = &[
+    (
+        1,
+        "CREATE TABLE IF NOT EXISTS users (\
+            id BIGINT PRIMARY KEY,\
+            city TEXT NOT NULL,\
+            language TEXT NOT NULL,\
+            temperature_unit TEXT NOT NULL,\
+            speed_unit TEXT NOT NULL,\
+            created_at TIMESTAMP NOT NULL,\
+            updated_at TIMESTAMP NOT NULL,\
+            last_active_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,\
+            active BOOLEAN NOT NULL DEFAULT TRUE\
+        )",
+    ),
+    (2, "ALTER TABLE users ADD COLUMN uv_alerts BOOLEAN NOT NULL DEFAULT FALSE"),
+    (3, "ALTER TABLE users ADD COLUMN uv_alert_sent_on DATE"),
+];
+
+/// Statement run first in every migration transaction on PostgreSQL.
+///
+/// The advisory lock is held until the transaction ends, so instances starting
+/// at the same time apply migrations one after another. The key is "weather" in ASCII.
+pub(super) const PG_LOCK: &str = "SELECT pg_advisory_xact_lock(33606991467799922)";
+
+/// Row of `schema_migrations`.
+#[derive(QueryableByName)]
+pub(super) struct AppliedMigration {
+    #[diesel(sql_type = Integer)]
+    version: i32,
+}
+
+/// Applies all pending [`MIGRATIONS`].
+///
+/// Each migration runs in one transaction together with its `schema_migrations` record,
+/// so a crash never leaves a migration applied but unrecorded.
+///
+/// # Arguments
+/// - `conn` - PostgreSQL or SQLite connection
+/// - `lock` - statement that serializes concurrent runs, [`PG_LOCK`] on PostgreSQL.
+///   SQLite databases are local files used by a single instance and need none.
+///
+/// # Returns
+/// - `Ok(())` if the schema is up to date
+/// - `Err` if a migration fails, it is rolled back and later migrations are not attempted
+pub async fn run<C>(conn: &mut C, lock: Option<&'static str>) -> Result<(), Box<dyn Error + Send + Sync>>
+where
+    C: AsyncConnection + Send,
+    SqlQuery: ExecuteDsl<C> + for<'q> LoadQuery<'q, C, AppliedMigration>,
+{
+    conn.transaction(|conn| async move {
+        if let Some(lock) = lock {
+            sql_query(lock).execute(conn).await?;
+        }
+
+        sql_query(
+            "CREATE TABLE IF NOT EXISTS schema_migrations (\
+                version INTEGER PRIMARY KEY,\
+                applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP\
+            )"
+        )
+            .execute(conn)
+            .await?;
+
+        Ok::<_, diesel::result::Error>(())
+    }.scope_boxed())
+        .await?;
+
+    for &(version, migration) in MIGRATIONS {
+        conn.transaction(|conn| async move {
+            if let Some(lock) = lock {
+                sql_query(lock).execute(conn).await?;
+            }
+
+            // Read under the lock, another instance may have just applied it
+            let applied = sql_query("SELECT version FROM schema_migrations")
+                .load::<AppliedMigration>(conn)
+                .await?;
+            if applied.iter().any(|m| m.version == version) {
+                return Ok(());
+            }
+
+            tracing::info!(version, "Applying database migration");
+            sql_query(migration).execute(conn).await?;
+            sql_query(format!("INSERT INTO schema_migrations (version) VALUES ({version})"))
+                .execute(conn)
+                .await?;
+
+            Ok::<_, diesel::result::Error>(())
+        }.scope_boxed())
+            .await?;
+    }
+
+    Ok(())
+}
@@ -1,4 +1,16 @@
-pub mod pool;
+mod memory;
+mod migrations;
 mod models;
-pub mod queries;
+pub mod pool;
+mod postgres;
+mod queries;
+mod repository;
 mod schema;
+mod sqlite;
+
+#[cfg(test)]
+mod tests;
+
+pub use memory::MemoryUserRepository;
+pub use models::User;
+pub use repository::Repository;
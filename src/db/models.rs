@@ -1,14 +1,15 @@
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use diesel::prelude::*;
+use serde::Serialize;
 
 use crate::enums::languages::Languages;
 use crate::enums::units::{SpeedUnits, TemperatureUnits};
 use super::schema::users;
 
 /// Модель користувача для читання з бази даних
-#[derive(Debug, Clone, Queryable, Selectable)]
+#[derive(Debug, Clone, Queryable, Selectable, Serialize)]
 #[diesel(table_name = users)]
-#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(check_for_backend(diesel::pg::Pg, diesel::sqlite::Sqlite))]
 pub struct User {
     pub id: i64,
     pub city: String,
@@ -17,6 +18,32 @@ pub struct User {
     pub speed_unit: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub last_active_at: NaiveDateTime,
+    /// `false` once the bot can no longer message the chat (e.g. blocked by the user)
+    pub active: bool,
+    /// `true` if the user asked to be alerted about a high UV index
+    pub uv_alerts: bool,
+    /// Local date in the user's city of the last UV alert sent
+    pub uv_alert_sent_on: Option<NaiveDate>,
+}
+
+/// Aggregated user statistics for admins
+#[derive(Debug, Clone)]
+pub struct UserStats {
+    /// Number of private chats (users)
+    pub users: i64,
+    /// Number of group chats
+    pub groups: i64,
+    /// Users with any activity in the requested period
+    pub active: i64,
+    /// Users and groups the bot can no longer message
+    pub inactive: i64,
+    /// `(language, count)` pairs, most used first
+    pub languages: Vec<(String, i64)>,
+    /// `(temperature unit, count)` pairs, most used first
+    pub temperature_units: Vec<(String, i64)>,
+    /// `(speed unit, count)` pairs, most used first
+    pub speed_units: Vec<(String, i64)>,
 }
 
 /// Модель для створення/оновлення користувача
@@ -1,41 +1,136 @@
 use std::error::Error;
 use std::sync::Arc;
 
-use diesel_async::{AsyncPgConnection, RunQueryDsl};
-use diesel_async::pooled_connection::AsyncDieselConnectionManager;
+use diesel::{ConnectionError, SqliteConnection};
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use diesel_async::pooled_connection::{AsyncDieselConnectionManager, ManagerConfig};
 use diesel_async::pooled_connection::deadpool::Pool;
+use diesel_async::sync_connection_wrapper::SyncConnectionWrapper;
+
+use super::migrations;
+use super::postgres::PgUserRepository;
+use super::repository::Repository;
+use super::sqlite::SqliteUserRepository;
 
 /// Type alias for the database connection pool
 pub type DbPool = Arc<Pool<AsyncPgConnection>>;
 
-/// Initialize the PostgreSQL database connection pool and create table if not exists
+/// Type alias for the SQLite connection pool
+pub type SqlitePool = Arc<Pool<SyncConnectionWrapper<SqliteConnection>>>;
+
+/// Storage backend selected by the `DATABASE_URL` scheme.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Backend {
+    /// `postgres://` or `postgresql://`
+    Postgres,
+    /// `sqlite://<path>`, e.g. `sqlite://weather.db` or `sqlite:///var/lib/weather.db`
+    Sqlite(String),
+}
+
+impl Backend {
+    /// Returns the backend for a connection string, `None` for unsupported schemes.
+    pub fn from_url(url: &str) -> Option<Self> {
+        if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+            return Some(Self::Postgres);
+        }
+
+        url.strip_prefix("sqlite://")
+            .filter(|path| !path.is_empty())
+            .map(|path| Self::Sqlite(path.to_string()))
+    }
+}
+
+/// Connects to the database selected by the URL scheme and applies pending migrations.
+///
+/// # Arguments
+/// - `database_url` - PostgreSQL or SQLite connection string, see [`Backend`]
+/// - `pool_size` - maximum number of pooled connections
+///
+/// # Returns
+/// - `Result<Repository, Box<dyn Error>>` - user repository on the selected backend
+pub async fn connect(database_url: &str, pool_size: usize) -> Result<Repository, Box<dyn Error>> {
+    match Backend::from_url(database_url) {
+        Some(Backend::Postgres) => Ok(Arc::new(PgUserRepository::new(init_db(database_url, pool_size).await?))),
+        Some(Backend::Sqlite(path)) => Ok(Arc::new(SqliteUserRepository::new(init_sqlite(&path, pool_size).await?))),
+        None => Err("unsupported database URL scheme".into()),
+    }
+}
+
+/// Initialize the PostgreSQL database connection pool and apply pending migrations
 ///
 /// # Arguments
 /// - `database_url` - PostgreSQL connection string
+/// - `pool_size` - maximum number of pooled connections
 ///
 /// # Returns
 /// - `Result<DbPool, Box<dyn Error> - Thread-safe connection pool
-pub async fn init_db(database_url: &str) -> Result<DbPool, Box<dyn Error>> {
+pub async fn init_db(database_url: &str, pool_size: usize) -> Result<DbPool, Box<dyn Error>> {
     let config = AsyncDieselConnectionManager::<AsyncPgConnection>::new(database_url);
 
     let pool = Pool::builder(config)
-        .max_size(10)
+        .max_size(pool_size)
         .build()?;
 
     let mut conn = pool.get().await?;
+
+    // Databases created before migrations were tracked got these columns at startup,
+    // bring them up to the first migration. No-ops for new and migrated databases.
+    diesel::sql_query(
+        "ALTER TABLE IF EXISTS users ADD COLUMN IF NOT EXISTS last_active_at TIMESTAMP NOT NULL DEFAULT now()"
+    )
+        .execute(&mut conn)
+        .await?;
     diesel::sql_query(
-        "CREATE TABLE IF NOT EXISTS users (\
-            id BIGINT PRIMARY KEY,\
-            city TEXT NOT NULL,\
-            language TEXT NOT NULL,\
-            temperature_unit TEXT NOT NULL,\
-            speed_unit TEXT NOT NULL,\
-            created_at TIMESTAMP NOT NULL,\
-            updated_at TIMESTAMP NOT NULL\
-        )"
+        "ALTER TABLE IF EXISTS users ADD COLUMN IF NOT EXISTS active BOOLEAN NOT NULL DEFAULT true"
     )
         .execute(&mut conn)
         .await?;
 
+    migrations::run(&mut *conn, Some(migrations::PG_LOCK)).await.map_err(|e| e as Box<dyn Error>)?;
+
+    Ok(Arc::new(pool))
+}
+
+/// Initialize the SQLite connection pool and apply pending migrations
+///
+/// The database file is created if it does not exist.
+///
+/// # Arguments
+/// - `path` - path to the database file
+/// - `pool_size` - maximum number of pooled connections
+///
+/// # Returns
+/// - `Result<SqlitePool, Box<dyn Error> - Thread-safe connection pool
+pub async fn init_sqlite(path: &str, pool_size: usize) -> Result<SqlitePool, Box<dyn Error>> {
+    let mut manager_config = ManagerConfig::default();
+    manager_config.custom_setup = Box::new(|path| Box::pin(establish_sqlite(path.to_string())));
+    let config = AsyncDieselConnectionManager::<SyncConnectionWrapper<SqliteConnection>>::new_with_config(
+        path,
+        manager_config,
+    );
+
+    let pool = Pool::builder(config)
+        .max_size(pool_size)
+        .build()?;
+
+    let mut conn = pool.get().await?;
+    // Lets readers work while another connection writes
+    diesel::sql_query("PRAGMA journal_mode = WAL")
+        .execute(&mut conn)
+        .await?;
+    migrations::run(&mut *conn, None).await.map_err(|e| e as Box<dyn Error>)?;
+
     Ok(Arc::new(pool))
 }
+
+/// Opens a SQLite connection that waits for locks held by other pooled connections.
+async fn establish_sqlite(path: String) -> Result<SyncConnectionWrapper<SqliteConnection>, ConnectionError> {
+    let mut conn = SyncConnectionWrapper::<SqliteConnection>::establish(&path).await?;
+
+    diesel::sql_query("PRAGMA busy_timeout = 5000")
+        .execute(&mut conn)
+        .await
+        .map_err(ConnectionError::CouldntSetupConfiguration)?;
+
+    Ok(conn)
+}
@@ -0,0 +1,17 @@
+use super::pool::DbPool;
+use super::queries::user_queries;
+
+/// User repository backed by PostgreSQL.
+pub struct PgUserRepository {
+    pool: DbPool,
+}
+
+impl PgUserRepository {
+    /// # Arguments
+    /// - `pool` - pool created by [`init_db`](crate::db::pool::init_db)
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+user_queries!(PgUserRepository);
@@ -1,158 +1,344 @@
-use std::error::Error;
-use chrono::Utc;
-
-use diesel::prelude::*;
-use diesel::ExpressionMethods;
-use diesel_async::RunQueryDsl;
-use crate::enums::languages::Languages;
-use crate::enums::units::{SpeedUnits, TemperatureUnits};
-use super::models::{User, UserData};
-use super::pool::DbPool;
-use super::schema::users;
-
-/// Provides database query methods related to user data.
-pub struct UserQueries;
-
-impl UserQueries {
-    /// Returns the full user record by ID.
-    ///
-    /// # Arguments
-    /// - `pool` - the database connection pool
-    /// - `user_id` - ID of the user
-    ///
-    /// # Returns
-    /// - `Some(UserData)` if found
-    /// - `None` if user does not exist or query fails
-    pub async fn get_user(pool: &DbPool, user_id: i64) -> Option<User> {
-        let mut conn = pool.get().await.ok()?;
-
-        users::table
-            .filter(users::id.eq(user_id))
-            .first::<User>(&mut conn)
-            .await
-            .ok()
-    }
-
-    /// Inserts of updates a user`s city in the database
-    ///
-    /// If the user does not exist, a new record is created.
-    /// If the user exists, their `city` and `updated_at` fields are updated.
-    ///
-    /// # Arguments
-    /// - `pool` - the database connection pool
-    /// - `user_id` - ID of the user
-    /// - `city` - the city to insert or update
-    ///
-    /// # Returns
-    /// - `Ok(())` on success, or an error if the operation fails.
-    pub async fn upsert_city(
-        pool: &DbPool,
-        user_id: i64,
-        city: &str
-    ) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let mut conn = pool.get().await?;
-        let now = Utc::now().naive_utc();
-
-        diesel::insert_into(users::table)
-            .values(&UserData {
-                id: user_id,
-                city: city.to_string(),
-                language: Languages::default().as_str().to_string(),
-                temperature_unit: TemperatureUnits::default().as_str().to_string(),
-                speed_unit: SpeedUnits::default().as_str().to_string(),
-                created_at: now,
-                updated_at: now,
-            })
-            .on_conflict(users::id)
-            .do_update()
-            .set((
-                users::city.eq(city),
-                users::updated_at.eq(now),
-            ))
-            .execute(&mut conn)
-            .await?;
-
-        Ok(())
-    }
-
-    /// Updates the language preference for a user.
-    ///
-    /// # Arguments
-    /// 
-    /// - `pool` - Shared database connection pool.
-    /// - `user_id` - The ID of the user to update.
-    /// - `lang` - the language code to set (e.g., "en", "uk").
-    ///
-    /// # Returns
-    ///
-    /// - `Ok(())` if the database succeed.
-    /// - `Err` if a database error occurs.
-    pub async fn set_lang(pool: &DbPool, user_id: i64, lang: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let mut conn = pool.get().await?;
-        let now = Utc::now().naive_utc();
-
-        diesel::update(users::table
-            .filter(users::id.eq(user_id)))
-            .set((
-                users::language.eq(lang),
-                users::updated_at.eq(now),
-            ))
-            .execute(&mut conn)
-            .await?;
-
-        Ok(())
-    }
-    
-    /// Updates the temperature unit preference for a user.
-    ///
-    /// # Arguments
-    ///
-    /// - `pool` - Shared database connection pool.
-    /// - `user_id` - The ID of the user to update.
-    /// - `temp` - The temperature unit to set (e.g., "C", "F", "K").
-    ///
-    /// # Returns
-    /// - `Ok(())` if the database succeed.
-    /// - `Err` if a database error occurs.
-    pub async fn set_temp_unit(pool: &DbPool, user_id: i64, temp: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let mut conn = pool.get().await?;
-        let now = Utc::now().naive_utc();
-        
-        diesel::update(users::table)
-            .filter(users::id.eq(user_id))
-            .set((
-                users::temperature_unit.eq(temp),
-                users::updated_at.eq(now),
-            ))
-            .execute(&mut conn)
-            .await?;
-        
-        Ok(())
-    }
-
-    /// Updates the speed unit preference for a user.
-    ///
-    /// # Arguments
-    /// - `pool` - Shared database connection pool.
-    /// - `user_id` - The ID of the user to update.
-    /// - `speed` - The temperature unit to set.
-    ///
-    /// # Returns
-    /// - `Ok(())` if the database succeed.
-    /// - `Err` if a database error occurs.
-    pub async fn set_speed_unit(pool: &DbPool, user_id: i64, speed: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let mut conn = pool.get().await?;
-        let now = Utc::now().naive_utc();
-        
-        diesel::update(users::table)
-            .filter(users::id.eq(user_id))
-            .set((
-                users::speed_unit.eq(speed),
-                users::updated_at.eq(now),
-            ))
-            .execute(&mut conn)
-            .await?;
-        
-        Ok(())
-    }
-}
\ No newline at end of file
+/// Implements [`UserRepository`](super::repository::UserRepository) for a struct
+/// with a `pool` of async diesel connections.
+///
+/// The queries are the same for every backend, but diesel type-checks them per
+/// connection type, so the implementation is expanded once for each backend.
+macro_rules! user_queries {
+    ($repository:ty) => {
+        #[async_trait::async_trait]
+        impl $crate::db::repository::UserRepository for $repository {
+            async fn get_user(&self, user_id: i64) -> Option<$crate::db::models::User> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::models::User;
+                use $crate::db::schema::users;
+                use $crate::logging::error_chain;
+
+                let mut conn = self.pool.get()
+                    .await
+                    .inspect_err(|e| tracing::error!(error = %error_chain(e), "Could not get database connection"))
+                    .ok()?;
+                let _timer = $crate::metrics::db_timer("get_user");
+
+                users::table
+                    .filter(users::id.eq(user_id))
+                    .first::<User>(&mut conn)
+                    .await
+                    .optional()
+                    .inspect_err(|e| tracing::error!(user_id, error = %error_chain(e), "Could not load user"))
+                    .ok()
+                    .flatten()
+            }
+
+            async fn upsert_city(
+                &self,
+                user_id: i64,
+                city: &str,
+                language: &str,
+            ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::models::UserData;
+                use $crate::db::schema::users;
+                use $crate::enums::units::{SpeedUnits, TemperatureUnits};
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("upsert_city");
+                let now = chrono::Utc::now().naive_utc();
+
+                diesel::insert_into(users::table)
+                    .values(&UserData {
+                        id: user_id,
+                        city: city.to_string(),
+                        language: language.to_string(),
+                        temperature_unit: TemperatureUnits::default().as_str().to_string(),
+                        speed_unit: SpeedUnits::default().as_str().to_string(),
+                        created_at: now,
+                        updated_at: now,
+                    })
+                    .on_conflict(users::id)
+                    .do_update()
+                    .set((
+                        users::city.eq(city),
+                        users::updated_at.eq(now),
+                    ))
+                    .execute(&mut conn)
+                    .await?;
+
+                Ok(())
+            }
+
+            async fn set_lang(&self, user_id: i64, lang: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("set_lang");
+                let now = chrono::Utc::now().naive_utc();
+
+                diesel::update(users::table)
+                    .filter(users::id.eq(user_id))
+                    .set((
+                        users::language.eq(lang),
+                        users::updated_at.eq(now),
+                    ))
+                    .execute(&mut conn)
+                    .await?;
+
+                Ok(())
+            }
+
+            async fn set_temp_unit(&self, user_id: i64, temp: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("set_temp_unit");
+                let now = chrono::Utc::now().naive_utc();
+
+                diesel::update(users::table)
+                    .filter(users::id.eq(user_id))
+                    .set((
+                        users::temperature_unit.eq(temp),
+                        users::updated_at.eq(now),
+                    ))
+                    .execute(&mut conn)
+                    .await?;
+
+                Ok(())
+            }
+
+            async fn set_speed_unit(&self, user_id: i64, speed: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("set_speed_unit");
+                let now = chrono::Utc::now().naive_utc();
+
+                diesel::update(users::table)
+                    .filter(users::id.eq(user_id))
+                    .set((
+                        users::speed_unit.eq(speed),
+                        users::updated_at.eq(now),
+                    ))
+                    .execute(&mut conn)
+                    .await?;
+
+                Ok(())
+            }
+
+            async fn set_uv_alerts(&self, user_id: i64, enabled: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("set_uv_alerts");
+                let now = chrono::Utc::now().naive_utc();
+
+                diesel::update(users::table)
+                    .filter(users::id.eq(user_id))
+                    .set((
+                        users::uv_alerts.eq(enabled),
+                        users::updated_at.eq(now),
+                    ))
+                    .execute(&mut conn)
+                    .await?;
+
+                Ok(())
+            }
+
+            async fn touch(&self, user_id: i64) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("touch");
+
+                diesel::update(users::table)
+                    .filter(users::id.eq(user_id))
+                    .set((
+                        users::last_active_at.eq(chrono::Utc::now().naive_utc()),
+                        users::active.eq(true),
+                    ))
+                    .execute(&mut conn)
+                    .await?;
+
+                Ok(())
+            }
+
+            async fn stats(
+                &self,
+                active_days: i64,
+            ) -> Result<$crate::db::models::UserStats, Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::dsl::count_star;
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::models::UserStats;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("stats");
+                let active_since = chrono::Utc::now().naive_utc() - chrono::Duration::days(active_days);
+
+                let users = users::table
+                    .filter(users::id.gt(0))
+                    .count()
+                    .get_result::<i64>(&mut conn)
+                    .await?;
+
+                let groups = users::table
+                    .filter(users::id.lt(0))
+                    .count()
+                    .get_result::<i64>(&mut conn)
+                    .await?;
+
+                let active = users::table
+                    .filter(users::id.gt(0))
+                    .filter(users::last_active_at.ge(active_since))
+                    .count()
+                    .get_result::<i64>(&mut conn)
+                    .await?;
+
+                let inactive = users::table
+                    .filter(users::active.eq(false))
+                    .count()
+                    .get_result::<i64>(&mut conn)
+                    .await?;
+
+                let languages = users::table
+                    .group_by(users::language)
+                    .select((users::language, count_star()))
+                    .order(count_star().desc())
+                    .load::<(String, i64)>(&mut conn)
+                    .await?;
+
+                let temperature_units = users::table
+                    .group_by(users::temperature_unit)
+                    .select((users::temperature_unit, count_star()))
+                    .order(count_star().desc())
+                    .load::<(String, i64)>(&mut conn)
+                    .await?;
+
+                let speed_units = users::table
+                    .group_by(users::speed_unit)
+                    .select((users::speed_unit, count_star()))
+                    .order(count_star().desc())
+                    .load::<(String, i64)>(&mut conn)
+                    .await?;
+
+                Ok(UserStats { users, groups, active, inactive, languages, temperature_units, speed_units })
+            }
+
+            async fn chat_ids(&self) -> Result<Vec<i64>, Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("chat_ids");
+
+                let ids = users::table
+                    .filter(users::active.eq(true))
+                    .select(users::id)
+                    .order(users::id)
+                    .load::<i64>(&mut conn)
+                    .await?;
+
+                Ok(ids)
+            }
+
+            async fn uv_alert_chats(
+                &self,
+            ) -> Result<Vec<$crate::db::models::User>, Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::models::User;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("uv_alert_chats");
+
+                let chats = users::table
+                    .filter(users::active.eq(true).and(users::uv_alerts.eq(true)))
+                    .order(users::id)
+                    .load::<User>(&mut conn)
+                    .await?;
+
+                Ok(chats)
+            }
+
+            async fn claim_uv_alert(
+                &self,
+                chat_id: i64,
+                date: chrono::NaiveDate,
+            ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("claim_uv_alert");
+
+                // A single conditional update, so concurrent instances can't both claim the day
+                let claimed = diesel::update(users::table)
+                    .filter(users::id.eq(chat_id))
+                    .filter(users::uv_alert_sent_on.is_null().or(users::uv_alert_sent_on.lt(date)))
+                    .set(users::uv_alert_sent_on.eq(date))
+                    .execute(&mut conn)
+                    .await?;
+
+                Ok(claimed > 0)
+            }
+
+            async fn set_active(&self, chat_id: i64, active: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("set_active");
+
+                diesel::update(users::table)
+                    .filter(users::id.eq(chat_id))
+                    .set(users::active.eq(active))
+                    .execute(&mut conn)
+                    .await?;
+
+                Ok(())
+            }
+
+            async fn delete_user(&self, user_id: i64) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use $crate::db::schema::users;
+
+                let mut conn = self.pool.get().await?;
+                let _timer = $crate::metrics::db_timer("delete_user");
+
+                let deleted = diesel::delete(users::table)
+                    .filter(users::id.eq(user_id))
+                    .execute(&mut conn)
+                    .await?;
+
+                Ok(deleted > 0)
+            }
+
+            /// Runs `SELECT 1` on a pooled connection.
+            async fn ping(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+                use diesel_async::RunQueryDsl;
+
+                let mut conn = self.pool.get().await?;
+                diesel::sql_query("SELECT 1").execute(&mut conn).await?;
+                Ok(())
+            }
+        }
+    };
+}
+
+pub(super) use user_queries;
@@ -0,0 +1,86 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use super::models::{User, UserStats};
+
+/// Shared user repository injected into handlers.
+pub type Repository = Arc<dyn UserRepository>;
+
+/// Storage for user and group settings.
+///
+/// Implemented for:
+/// - [`PgUserRepository`](super::postgres::PgUserRepository) (PostgreSQL)
+/// - [`SqliteUserRepository`](super::sqlite::SqliteUserRepository) (local SQLite file)
+/// - [`MemoryUserRepository`](super::memory::MemoryUserRepository) (process memory, lost on restart)
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    /// Returns the full user record by ID.
+    ///
+    /// # Returns
+    /// - `Some(User)` if found
+    /// - `None` if user does not exist or the lookup fails (failures are logged)
+    async fn get_user(&self, user_id: i64) -> Option<User>;
+
+    /// Inserts or updates a user`s city.
+    ///
+    /// A new record gets `language` and the default units, an existing one
+    /// keeps its language and units and only changes `city` and `updated_at`.
+    async fn upsert_city(&self, user_id: i64, city: &str, language: &str) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Updates the language preference, does nothing for unknown users.
+    async fn set_lang(&self, user_id: i64, lang: &str) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Updates the temperature unit preference, does nothing for unknown users.
+    async fn set_temp_unit(&self, user_id: i64, temp: &str) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Updates the speed unit preference, does nothing for unknown users.
+    async fn set_speed_unit(&self, user_id: i64, speed: &str) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Turns the high UV index alert on or off, does nothing for unknown users.
+    async fn set_uv_alerts(&self, user_id: i64, enabled: bool) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Records that a user interacted with the bot and marks them active again.
+    ///
+    /// Does nothing if the user has no stored record.
+    async fn touch(&self, user_id: i64) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Returns aggregated statistics over all stored users and groups.
+    ///
+    /// # Arguments
+    /// - `active_days` - Period in days for counting active users.
+    async fn stats(&self, active_days: i64) -> Result<UserStats, Box<dyn Error + Send + Sync>>;
+
+    /// Returns IDs of all active chats (users and groups), ordered by ID.
+    async fn chat_ids(&self) -> Result<Vec<i64>, Box<dyn Error + Send + Sync>>;
+
+    /// Returns active chats that turned on UV alerts, ordered by ID.
+    async fn uv_alert_chats(&self) -> Result<Vec<User>, Box<dyn Error + Send + Sync>>;
+
+    /// Records that the UV alert for `date` is being sent to the chat.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if no alert was sent for `date` or later yet
+    /// - `Ok(false)` if it was, e.g. by another instance of the bot, or the chat is unknown
+    async fn claim_uv_alert(&self, chat_id: i64, date: NaiveDate) -> Result<bool, Box<dyn Error + Send + Sync>>;
+
+    /// Marks a chat as active or inactive.
+    ///
+    /// # Arguments
+    /// - `chat_id` - ID of the user or group.
+    /// - `active` - `false` if the bot can no longer message the chat.
+    async fn set_active(&self, chat_id: i64, active: bool) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Removes everything stored about a user or group.
+    ///
+    /// Any data added later that is keyed by the chat ID must be removed here too.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if a record was removed, `Ok(false)` if there was none.
+    async fn delete_user(&self, user_id: i64) -> Result<bool, Box<dyn Error + Send + Sync>>;
+
+    /// Checks that the storage is reachable, used by the readiness probe.
+    async fn ping(&self) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
@@ -1,4 +1,7 @@
 diesel::table! {
+    /// Settings of users and group chats.
+    ///
+    /// Group chats are stored under their (negative) chat ID.
     users (id) {
         id -> Int8,              // BIGINT PRIMARY KEY
         city -> Text,            // TEXT NOT NULL
@@ -7,5 +10,9 @@ diesel::table! {
         speed_unit -> Text,       // TEXT NOT NULL
         created_at -> Timestamp, // TIMESTAMP NOT NULL
         updated_at -> Timestamp, // TIMESTAMP NOT NULL
+        last_active_at -> Timestamp, // TIMESTAMP NOT NULL DEFAULT now()
+        active -> Bool,          // BOOLEAN NOT NULL DEFAULT true
+        uv_alerts -> Bool,       // BOOLEAN NOT NULL DEFAULT false
+        uv_alert_sent_on -> Nullable<Date>, // DATE
     }
 }
\ No newline at end of file
@@ -0,0 +1,17 @@
+use super::pool::SqlitePool;
+use super::queries::user_queries;
+
+/// User repository backed by a local SQLite file.
+pub struct SqliteUserRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteUserRepository {
+    /// # Arguments
+    /// - `pool` - pool created by [`init_sqlite`](crate::db::pool::init_sqlite)
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+user_queries!(SqliteUserRepository);
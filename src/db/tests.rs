@@ -0,0 +1,298 @@
+//! Tests of the user repositories.
+//!
+//! Every test runs against the in-memory repository, a temporary SQLite file and,
+//! if `TEST_DATABASE_URL` is set, against PostgreSQL. Every PostgreSQL run gets its
+//! own throwaway schema, so tests run in parallel and leave nothing behind.
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+
+use crate::db::pool::{init_db, init_sqlite, DbPool, SqlitePool};
+use crate::db::postgres::PgUserRepository;
+use crate::db::sqlite::SqliteUserRepository;
+use crate::db::{MemoryUserRepository, Repository};
+use crate::enums::languages::Languages;
+use crate::enums::units::{SpeedUnits, TemperatureUnits};
+
+static NEXT_DATABASE: AtomicU32 = AtomicU32::new(0);
+
+/// Connection pool bound to a fresh schema, dropped together with all its data.
+struct TestDb {
+    pool: DbPool,
+    /// URL of the schema, for extra pools
+    url: String,
+    base_url: String,
+    schema: String,
+}
+
+impl TestDb {
+    /// Creates a unique schema and the tables in it.
+    ///
+    /// # Returns
+    /// `None` if `TEST_DATABASE_URL` is not set.
+    async fn new() -> Option<Self> {
+        let base_url = std::env::var("TEST_DATABASE_URL").ok()?;
+
+        let schema = format!(
+            "test_{}_{}",
+            std::process::id(),
+            NEXT_DATABASE.fetch_add(1, Ordering::Relaxed),
+        );
+
+        let mut conn = AsyncPgConnection::establish(&base_url).await.expect("test database is not available");
+        diesel::sql_query(format!("CREATE SCHEMA {schema}"))
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        // Unqualified table names resolve to the test schema only
+        let separator = if base_url.contains('?') { '&' } else { '?' };
+        let url = format!("{base_url}{separator}options=-c%20search_path%3D{schema}");
+        let pool = init_db(&url, 2).await.unwrap();
+
+        Some(Self { pool, url, base_url, schema })
+    }
+}
+
+/// SQLite database in a temporary file, removed on drop.
+struct TestSqlite {
+    pool: SqlitePool,
+    path: PathBuf,
+}
+
+impl TestSqlite {
+    async fn new() -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "weather-test-{}-{}.db",
+            std::process::id(),
+            NEXT_DATABASE.fetch_add(1, Ordering::Relaxed),
+        ));
+        let pool = init_sqlite(path.to_str().unwrap(), 2).await.unwrap();
+
+        Self { pool, path }
+    }
+}
+
+impl Drop for TestSqlite {
+    fn drop(&mut self) {
+        for suffix in ["", "-wal", "-shm"] {
+            let mut path = self.path.clone().into_os_string();
+            path.push(suffix);
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// Runs `check` against every available repository, each starting empty.
+async fn for_each_repository<F, Fut>(check: F)
+where
+    F: Fn(Repository) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    check(Arc::new(MemoryUserRepository::default())).await;
+
+    let sqlite = TestSqlite::new().await;
+    check(Arc::new(SqliteUserRepository::new(sqlite.pool.clone()))).await;
+
+    match TestDb::new().await {
+        Some(db) => check(Arc::new(PgUserRepository::new(db.pool.clone()))).await,
+        None => eprintln!("TEST_DATABASE_URL is not set, skipping PostgreSQL"),
+    }
+}
+
+impl Drop for TestDb {
+    fn drop(&mut self) {
+        let base_url = self.base_url.clone();
+        let schema = self.schema.clone();
+
+        // Drop runs inside the test's runtime, so clean up on a separate one
+        let cleanup = std::thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(async {
+                    let mut conn = AsyncPgConnection::establish(&base_url).await.map_err(|e| e.to_string())?;
+                    diesel::sql_query(format!("DROP SCHEMA {schema} CASCADE"))
+                        .execute(&mut conn)
+                        .await
+                        .map_err(|e| e.to_string())
+                })
+        });
+
+        if let Ok(Err(e)) = cleanup.join() {
+            eprintln!("Could not drop test schema {}: {e}", self.schema);
+        }
+    }
+}
+
+#[tokio::test]
+async fn concurrent_migrations_are_applied_once() {
+    let Some(db) = TestDb::new().await else {
+        eprintln!("TEST_DATABASE_URL is not set, skipping PostgreSQL");
+        return;
+    };
+
+    // Start over from an empty schema, as several instances deploying at once would
+    let mut conn = db.pool.get().await.unwrap();
+    diesel::sql_query("DROP TABLE users, schema_migrations")
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    drop(conn);
+
+    let (first, second, third) = tokio::join!(init_db(&db.url, 1), init_db(&db.url, 1), init_db(&db.url, 1));
+    for result in [first, second, third] {
+        assert!(result.is_ok(), "migrations failed: {:?}", result.err());
+    }
+
+    let repo: Repository = Arc::new(PgUserRepository::new(db.pool.clone()));
+    repo.upsert_city(1, "Kyiv", "en").await.unwrap();
+    assert_eq!(repo.get_user(1).await.unwrap().city, "Kyiv");
+}
+
+#[tokio::test]
+async fn upsert_city_creates_user_with_given_language() {
+    for_each_repository(|db| async move {
+        db.upsert_city(1, "Kyiv", "en").await.unwrap();
+
+        let user = db.get_user(1).await.unwrap();
+        assert_eq!(user.city, "Kyiv");
+        assert_eq!(user.language, "en");
+        assert_eq!(user.temperature_unit, TemperatureUnits::default().as_str());
+        assert_eq!(user.speed_unit, SpeedUnits::default().as_str());
+        assert!(user.active);
+        assert!(!user.uv_alerts);
+    }).await;
+}
+
+#[tokio::test]
+async fn uv_alerts_are_opt_in() {
+    for_each_repository(|db| async move {
+        db.upsert_city(1, "Kyiv", Languages::default().as_str()).await.unwrap();
+
+        db.set_uv_alerts(1, true).await.unwrap();
+        assert!(db.get_user(1).await.unwrap().uv_alerts);
+
+        db.set_uv_alerts(1, false).await.unwrap();
+        assert!(!db.get_user(1).await.unwrap().uv_alerts);
+    }).await;
+}
+
+#[tokio::test]
+async fn uv_alert_is_claimed_once_per_day() {
+    for_each_repository(|db| async move {
+        db.upsert_city(1, "Kyiv", Languages::default().as_str()).await.unwrap();
+        db.upsert_city(2, "Lviv", Languages::default().as_str()).await.unwrap();
+        db.upsert_city(-100, "Odesa", Languages::default().as_str()).await.unwrap();
+        db.set_uv_alerts(2, true).await.unwrap();
+        db.set_uv_alerts(-100, true).await.unwrap();
+        db.set_active(-100, false).await.unwrap();
+
+        let chats = db.uv_alert_chats().await.unwrap();
+        assert_eq!(chats.iter().map(|u| u.id).collect::<Vec<_>>(), [2]);
+
+        let today = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+        assert!(db.claim_uv_alert(2, today).await.unwrap());
+        assert!(!db.claim_uv_alert(2, today).await.unwrap());
+        assert!(!db.claim_uv_alert(2, today.pred_opt().unwrap()).await.unwrap());
+        assert!(db.claim_uv_alert(2, today.succ_opt().unwrap()).await.unwrap());
+        assert_eq!(db.get_user(2).await.unwrap().uv_alert_sent_on, today.succ_opt());
+
+        assert!(!db.claim_uv_alert(3, today).await.unwrap());
+    }).await;
+}
+
+#[tokio::test]
+async fn upsert_city_keeps_language_and_units() {
+    for_each_repository(|db| async move {
+        db.upsert_city(1, "Kyiv", Languages::default().as_str()).await.unwrap();
+        db.set_lang(1, "de").await.unwrap();
+        db.set_temp_unit(1, TemperatureUnits::Fahrenheit.as_str()).await.unwrap();
+        db.set_speed_unit(1, SpeedUnits::Knots.as_str()).await.unwrap();
+        let before = db.get_user(1).await.unwrap();
+
+        db.upsert_city(1, "Berlin", "en").await.unwrap();
+
+        let user = db.get_user(1).await.unwrap();
+        assert_eq!(user.city, "Berlin");
+        assert_eq!(user.language, "de");
+        assert_eq!(user.temperature_unit, TemperatureUnits::Fahrenheit.as_str());
+        assert_eq!(user.speed_unit, SpeedUnits::Knots.as_str());
+        assert_eq!(user.created_at, before.created_at);
+        assert!(user.updated_at >= before.updated_at);
+    }).await;
+}
+
+#[tokio::test]
+async fn get_user_returns_none_for_unknown_id() {
+    for_each_repository(|db| async move {
+        assert!(db.get_user(42).await.is_none());
+    }).await;
+}
+
+#[tokio::test]
+async fn settings_of_unknown_user_are_not_created() {
+    for_each_repository(|db| async move {
+        db.set_lang(1, "en").await.unwrap();
+        db.touch(1).await.unwrap();
+
+        assert!(db.get_user(1).await.is_none());
+    }).await;
+}
+
+#[tokio::test]
+async fn delete_user_removes_only_that_user() {
+    for_each_repository(|db| async move {
+        db.upsert_city(1, "Kyiv", Languages::default().as_str()).await.unwrap();
+        db.upsert_city(2, "Lviv", Languages::default().as_str()).await.unwrap();
+
+        assert!(db.delete_user(1).await.unwrap());
+        assert!(!db.delete_user(1).await.unwrap());
+
+        assert!(db.get_user(1).await.is_none());
+        assert!(db.get_user(2).await.is_some());
+        assert_eq!(db.chat_ids().await.unwrap(), [2]);
+    }).await;
+}
+
+#[tokio::test]
+async fn inactive_chats_are_skipped_until_touched() {
+    for_each_repository(|db| async move {
+        db.upsert_city(2, "Lviv", Languages::default().as_str()).await.unwrap();
+        db.upsert_city(1, "Kyiv", Languages::default().as_str()).await.unwrap();
+        db.upsert_city(-100, "Odesa", Languages::default().as_str()).await.unwrap();
+        assert_eq!(db.chat_ids().await.unwrap(), [-100, 1, 2]);
+
+        db.set_active(1, false).await.unwrap();
+        assert_eq!(db.chat_ids().await.unwrap(), [-100, 2]);
+
+        db.touch(1).await.unwrap();
+        assert_eq!(db.chat_ids().await.unwrap(), [-100, 1, 2]);
+    }).await;
+}
+
+#[tokio::test]
+async fn stats_count_users_groups_and_settings() {
+    for_each_repository(|db| async move {
+        db.upsert_city(1, "Kyiv", Languages::default().as_str()).await.unwrap();
+        db.upsert_city(2, "Lviv", Languages::default().as_str()).await.unwrap();
+        db.upsert_city(-100, "Odesa", Languages::default().as_str()).await.unwrap();
+        db.set_lang(2, "en").await.unwrap();
+        db.set_lang(-100, "en").await.unwrap();
+        db.set_active(1, false).await.unwrap();
+
+        let stats = db.stats(7).await.unwrap();
+        assert_eq!(stats.users, 2);
+        assert_eq!(stats.groups, 1);
+        assert_eq!(stats.active, 2);
+        assert_eq!(stats.inactive, 1);
+        assert_eq!(stats.languages[0], ("en".to_string(), 2));
+        assert_eq!(stats.temperature_units, [(TemperatureUnits::default().as_str().to_string(), 3)]);
+    }).await;
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+use teloxide::{ApiError, RequestError};
+use tokio::time::{sleep_until, Instant};
+
+use crate::db::Repository;
+use crate::logging::error_chain;
+
+/// Minimum delay between messages to the same private chat (Telegram allows about 1 per second).
+const PRIVATE_CHAT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Minimum delay between messages to the same group (Telegram allows 20 per minute).
+const GROUP_CHAT_INTERVAL: Duration = Duration::from_secs(3);
+
+/// How many times a request is retried after a `RetryAfter` response.
+const MAX_RETRIES: u32 = 3;
+
+/// Result of a delivery attempt that reached Telegram.
+#[derive(Debug)]
+pub enum Delivered<T> {
+    /// Request succeeded.
+    Sent(T),
+    /// The chat can no longer be reached (bot blocked, kicked or user deleted).
+    /// The chat is marked inactive and skipped by future broadcasts.
+    Unreachable,
+}
+
+/// Outbound message queue wrapping [`Bot`].
+///
+/// Requests wait for a free slot under the global and per-chat rate limits,
+/// are retried when Telegram answers with `RetryAfter`, and chats that blocked
+/// the bot are marked inactive in the database.
+///
+/// Cheap to clone, all clones share the same limits.
+#[derive(Clone)]
+pub struct Delivery {
+    bot: Bot,
+    db: Repository,
+    global_interval: Duration,
+    schedule: Arc<Mutex<Schedule>>,
+}
+
+/// Next free send time, globally and per chat.
+#[derive(Default)]
+struct Schedule {
+    global: Option<Instant>,
+    chats: HashMap<i64, Instant>,
+}
+
+impl Delivery {
+    /// Creates a delivery queue.
+    ///
+    /// # Arguments
+    /// - `bot` - bot used to send requests
+    /// - `db` - user repository for marking unreachable chats
+    /// - `messages_per_second` - global rate limit
+    pub fn new(bot: Bot, db: Repository, messages_per_second: u32) -> Self {
+        Self {
+            bot,
+            db,
+            global_interval: Duration::from_secs(1) / messages_per_second.max(1),
+            schedule: Arc::new(Mutex::new(Schedule::default())),
+        }
+    }
+
+    /// Sends an HTML text message through the queue.
+    pub async fn send_message(&self, chat_id: ChatId, text: &str) -> Result<Delivered<Message>, RequestError> {
+        self.deliver(chat_id, || {
+            self.bot.send_message(chat_id, text).parse_mode(ParseMode::Html).send()
+        })
+            .await
+    }
+
+    /// Runs a request to `chat_id` through the queue.
+    ///
+    /// `request` is called again for every retry.
+    pub async fn deliver<T, F, Fut>(&self, chat_id: ChatId, request: F) -> Result<Delivered<T>, RequestError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, RequestError>>,
+    {
+        let mut retries = 0;
+
+        loop {
+            sleep_until(self.reserve(chat_id)).await;
+
+            match request().await {
+                Ok(value) => return Ok(Delivered::Sent(value)),
+                Err(RequestError::RetryAfter(seconds)) if retries < MAX_RETRIES => {
+                    retries += 1;
+                    tracing::warn!(chat_id = chat_id.0, seconds = seconds.seconds(), "Flood limit hit, retrying");
+                    self.pause(seconds.duration());
+                }
+                Err(RequestError::Api(e)) if is_unreachable(&e) => {
+                    tracing::info!(chat_id = chat_id.0, error = %e, "Chat is unreachable, marking inactive");
+                    if let Err(e) = self.db.set_active(chat_id.0, false).await {
+                        tracing::error!(chat_id = chat_id.0, error = %error_chain(e.as_ref()), "Could not mark chat inactive");
+                    }
+                    return Ok(Delivered::Unreachable);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Reserves the next send slot for the chat and returns when it starts.
+    fn reserve(&self, chat_id: ChatId) -> Instant {
+        let mut schedule = self.schedule.lock().unwrap();
+        let now = Instant::now();
+
+        // Forget chats whose slot has passed, so the map does not grow forever
+        schedule.chats.retain(|_, next| *next > now);
+
+        let slot = [Some(now), schedule.global, schedule.chats.get(&chat_id.0).copied()]
+            .into_iter()
+            .flatten()
+            .max()
+            .unwrap_or(now);
+
+        let chat_interval = if chat_id.is_user() { PRIVATE_CHAT_INTERVAL } else { GROUP_CHAT_INTERVAL };
+
+        schedule.global = Some(slot + self.global_interval);
+        schedule.chats.insert(chat_id.0, slot + chat_interval);
+
+        slot
+    }
+
+    /// Delays all further requests, used when Telegram asks to slow down.
+    fn pause(&self, duration: Duration) {
+        let mut schedule = self.schedule.lock().unwrap();
+        let resume = Instant::now() + duration;
+        schedule.global = Some(schedule.global.map_or(resume, |next| next.max(resume)));
+    }
+}
+
+/// Returns `true` for errors meaning the bot can no longer write to the chat.
+fn is_unreachable(error: &ApiError) -> bool {
+    match error {
+        ApiError::BotBlocked
+        | ApiError::UserDeactivated
+        | ApiError::BotKicked
+        | ApiError::BotKickedFromSupergroup
+        | ApiError::BotKickedFromChannel
+        | ApiError::CantInitiateConversation => true,
+        ApiError::Unknown(description) => description.starts_with("Forbidden:"),
+        _ => false,
+    }
+}
@@ -0,0 +1,76 @@
+/// Air quality index bands (1 = good, 5 = very poor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AirQualityIndex {
+    Good,
+    Fair,
+    Moderate,
+    Poor,
+    VeryPoor,
+}
+
+impl AirQualityIndex {
+    /// Maps a numeric index from 1 to 5 to the band.
+    pub fn from_index(index: u8) -> Option<Self> {
+        match index {
+            1 => Some(AirQualityIndex::Good),
+            2 => Some(AirQualityIndex::Fair),
+            3 => Some(AirQualityIndex::Moderate),
+            4 => Some(AirQualityIndex::Poor),
+            5 => Some(AirQualityIndex::VeryPoor),
+            _ => None,
+        }
+    }
+
+    /// Maps a European AQI value (0-100+) to the band.
+    pub fn from_european_aqi(aqi: f64) -> Self {
+        match aqi {
+            x if x < 20.0 => AirQualityIndex::Good,
+            x if x < 40.0 => AirQualityIndex::Fair,
+            x if x < 60.0 => AirQualityIndex::Moderate,
+            x if x < 80.0 => AirQualityIndex::Poor,
+            _ => AirQualityIndex::VeryPoor,
+        }
+    }
+
+    pub fn as_index(&self) -> u8 {
+        match self {
+            AirQualityIndex::Good => 1,
+            AirQualityIndex::Fair => 2,
+            AirQualityIndex::Moderate => 3,
+            AirQualityIndex::Poor => 4,
+            AirQualityIndex::VeryPoor => 5,
+        }
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            AirQualityIndex::Good => "🟢",
+            AirQualityIndex::Fair => "🟡",
+            AirQualityIndex::Moderate => "🟠",
+            AirQualityIndex::Poor => "🔴",
+            AirQualityIndex::VeryPoor => "🟣",
+        }
+    }
+
+    /// Returns the locale key of the category name.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            AirQualityIndex::Good => "aqi-good",
+            AirQualityIndex::Fair => "aqi-fair",
+            AirQualityIndex::Moderate => "aqi-moderate",
+            AirQualityIndex::Poor => "aqi-poor",
+            AirQualityIndex::VeryPoor => "aqi-very-poor",
+        }
+    }
+
+    /// Returns the locale key of the health advice.
+    pub fn advice_key(&self) -> &'static str {
+        match self {
+            AirQualityIndex::Good => "aqi-advice-good",
+            AirQualityIndex::Fair => "aqi-advice-fair",
+            AirQualityIndex::Moderate => "aqi-advice-moderate",
+            AirQualityIndex::Poor => "aqi-advice-poor",
+            AirQualityIndex::VeryPoor => "aqi-advice-very-poor",
+        }
+    }
+}
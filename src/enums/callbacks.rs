@@ -1,17 +1,27 @@
+use crate::enums::languages::Languages;
+
+#[derive(Clone, Copy)]
 pub enum Callbacks {
     Start,
     Today,
     Tomorrow,
+    Sun,
+    AirQuality,
+    Chart,
 
     // Settings
     SettingsHub,
     SelectLanguage,
     SelectUnits,
+    ChangeCity,
+    UvAlerts,
+
+    // Account deletion
+    DeleteConfirm,
+    DeleteCancel,
     
     // Languages
-    English,
-    Ukrainian,
-    Deutsch,
+    Language(Languages),
 
     // Units
     Temperature,
@@ -30,17 +40,48 @@ pub enum Callbacks {
 }
 
 impl Callbacks {
+    /// Every button except the language ones, which come from `locales/`.
+    const ALL: &[Callbacks] = &[
+        Callbacks::Start,
+        Callbacks::Today,
+        Callbacks::Tomorrow,
+        Callbacks::Sun,
+        Callbacks::AirQuality,
+        Callbacks::Chart,
+        Callbacks::SettingsHub,
+        Callbacks::SelectLanguage,
+        Callbacks::SelectUnits,
+        Callbacks::ChangeCity,
+        Callbacks::UvAlerts,
+        Callbacks::DeleteConfirm,
+        Callbacks::DeleteCancel,
+        Callbacks::Temperature,
+        Callbacks::Speed,
+        Callbacks::Celsius,
+        Callbacks::Fahrenheit,
+        Callbacks::Kelvin,
+        Callbacks::KilometersPerHour,
+        Callbacks::MetersPerMinute,
+        Callbacks::MilesPerHour,
+        Callbacks::Knots,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Callbacks::Start => "start",
             Callbacks::Today => "today",
             Callbacks::Tomorrow => "tomorrow",
+            Callbacks::Sun => "sun",
+            Callbacks::AirQuality => "air-quality",
+            Callbacks::Chart => "chart",
             Callbacks::SettingsHub => "settings-hub",
             Callbacks::SelectLanguage => "select-language",
             Callbacks::SelectUnits => "select-units",
-            Callbacks::English => "english",
-            Callbacks::Ukrainian => "ukrainian",
-            Callbacks::Deutsch => "deutsch",
+            Callbacks::ChangeCity => "change-city",
+            Callbacks::UvAlerts => "uv-alerts",
+            Callbacks::DeleteConfirm => "delete-confirm",
+            Callbacks::DeleteCancel => "delete-cancel",
+            Callbacks::Language(lang) => lang.callback_data(),
             Callbacks::Temperature => "temperature",
             Callbacks::Speed => "speed",
             Callbacks::Celsius => "celsius",
@@ -52,4 +93,26 @@ impl Callbacks {
             Callbacks::Knots => "knots",
         }
     }
+
+    /// Returns `true` for buttons that request data from the weather provider.
+    pub fn fetches_weather(&self) -> bool {
+        matches!(
+            self,
+            Callbacks::Today | Callbacks::Tomorrow | Callbacks::Sun | Callbacks::AirQuality | Callbacks::Chart
+        )
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        if let Some(callback) = Self::ALL.iter().find(|c| c.as_str() == s) {
+            return Some(*callback);
+        }
+
+        match s {
+            // Buttons sent before the languages were read from `locales/`
+            "english" => Languages::from_str("en").map(Callbacks::Language),
+            "ukrainian" => Languages::from_str("uk").map(Callbacks::Language),
+            "deutsch" => Languages::from_str("de").map(Callbacks::Language),
+            &_ => Languages::from_callback_data(s).map(Callbacks::Language),
+        }
+    }
 }
@@ -5,4 +5,22 @@ use teloxide::utils::command::BotCommands;
 pub enum Commands {
     #[command(description = "Start the bot and show welcome prompt")]
     Start,
+    #[command(description = "Show today's weather for this chat's city")]
+    Weather,
+    #[command(description = "Delete everything the bot stores about you")]
+    DeleteMe,
+    #[command(description = "Export everything the bot stores about you as JSON")]
+    Export,
+}
+
+/// Commands available only to the admins listed in `ADMIN_IDS`.
+#[derive(BotCommands, Clone)]
+#[command(rename_rule = "lowercase", description = "Admin commands:")]
+pub enum AdminCommands {
+    #[command(description = "Show user statistics")]
+    Stats,
+    #[command(description = "Send a message to all users and groups")]
+    Broadcast(String),
+    #[command(description = "Show the stored record of a user or group")]
+    User(String),
 }
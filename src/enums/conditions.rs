@@ -0,0 +1,178 @@
+/// Weather condition groups reported by the provider as numeric codes.
+///
+/// Codes follow the OpenWeatherMap condition table
+/// (<https://openweathermap.org/weather-conditions>), so the mapping does not
+/// depend on the language the description was requested in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeatherCondition {
+    Thunderstorm,
+    Drizzle,
+    Rain,
+    FreezingRain,
+    ShowerRain,
+    Snow,
+    Sleet,
+    ShowerSnow,
+    Mist,
+    Smoke,
+    Haze,
+    Dust,
+    Fog,
+    Ash,
+    Squall,
+    Tornado,
+    Clear,
+    FewClouds,
+    ScatteredClouds,
+    BrokenClouds,
+    Overcast,
+    Unknown,
+}
+
+impl WeatherCondition {
+    /// Maps a provider condition code (e.g. `500`, `801`) to a condition group.
+    pub fn from_code(code: u16) -> Self {
+        match code {
+            200..=232 => WeatherCondition::Thunderstorm,
+            300..=321 => WeatherCondition::Drizzle,
+            500..=504 => WeatherCondition::Rain,
+            511 => WeatherCondition::FreezingRain,
+            520..=531 => WeatherCondition::ShowerRain,
+            600..=602 => WeatherCondition::Snow,
+            611..=616 => WeatherCondition::Sleet,
+            620..=622 => WeatherCondition::ShowerSnow,
+            701 => WeatherCondition::Mist,
+            711 => WeatherCondition::Smoke,
+            721 => WeatherCondition::Haze,
+            731 | 751 | 761 => WeatherCondition::Dust,
+            741 => WeatherCondition::Fog,
+            762 => WeatherCondition::Ash,
+            771 => WeatherCondition::Squall,
+            781 => WeatherCondition::Tornado,
+            800 => WeatherCondition::Clear,
+            801 => WeatherCondition::FewClouds,
+            802 => WeatherCondition::ScatteredClouds,
+            803 => WeatherCondition::BrokenClouds,
+            804 => WeatherCondition::Overcast,
+            _ => WeatherCondition::Unknown,
+        }
+    }
+
+    /// Maps a WMO weather interpretation code (used by Open-Meteo) to the
+    /// equivalent provider condition code.
+    pub fn code_from_wmo(code: u8) -> u16 {
+        match code {
+            0 => 800,
+            1 => 801,
+            2 => 802,
+            3 => 804,
+            45 | 48 => 741,
+            51 => 300,
+            53 => 301,
+            55 => 302,
+            56 | 57 | 66 | 67 => 511,
+            61 => 500,
+            63 => 501,
+            65 => 502,
+            71 | 77 => 600,
+            73 => 601,
+            75 => 602,
+            80 => 520,
+            81 => 521,
+            82 => 522,
+            85 => 620,
+            86 => 621,
+            95 => 211,
+            96 | 99 => 202,
+            _ => 0,
+        }
+    }
+
+    /// Returns the provider icon number (without the day/night suffix) for the condition.
+    pub fn icon(&self) -> &'static str {
+        match self {
+            WeatherCondition::Clear => "01",
+            WeatherCondition::FewClouds => "02",
+            WeatherCondition::ScatteredClouds => "03",
+            WeatherCondition::BrokenClouds | WeatherCondition::Overcast => "04",
+            WeatherCondition::Drizzle | WeatherCondition::ShowerRain => "09",
+            WeatherCondition::Rain => "10",
+            WeatherCondition::Thunderstorm => "11",
+            WeatherCondition::FreezingRain
+            | WeatherCondition::Snow
+            | WeatherCondition::Sleet
+            | WeatherCondition::ShowerSnow => "13",
+            WeatherCondition::Mist
+            | WeatherCondition::Smoke
+            | WeatherCondition::Haze
+            | WeatherCondition::Dust
+            | WeatherCondition::Fog
+            | WeatherCondition::Ash
+            | WeatherCondition::Squall
+            | WeatherCondition::Tornado
+            | WeatherCondition::Unknown => "50",
+        }
+    }
+
+    /// Returns the emoji for the condition, using night variants where they differ.
+    pub fn emoji(&self, is_night: bool) -> &'static str {
+        match (self, is_night) {
+            (WeatherCondition::Thunderstorm, _) => "⛈️",
+            (WeatherCondition::Drizzle, false) => "🌦️",
+            (WeatherCondition::Drizzle, true) => "🌧️",
+            (WeatherCondition::Rain, _) => "🌧️",
+            (WeatherCondition::FreezingRain, _) => "🧊",
+            (WeatherCondition::ShowerRain, false) => "🌦️",
+            (WeatherCondition::ShowerRain, true) => "🌧️",
+            (WeatherCondition::Snow, _) => "❄️",
+            (WeatherCondition::Sleet, _) => "🌨️",
+            (WeatherCondition::ShowerSnow, _) => "🌨️",
+            (WeatherCondition::Mist, _) => "🌫️",
+            (WeatherCondition::Smoke, _) => "💨",
+            (WeatherCondition::Haze, _) => "🌫️",
+            (WeatherCondition::Dust, _) => "🏜️",
+            (WeatherCondition::Fog, _) => "🌫️",
+            (WeatherCondition::Ash, _) => "🌋",
+            (WeatherCondition::Squall, _) => "💨",
+            (WeatherCondition::Tornado, _) => "🌪️",
+            (WeatherCondition::Clear, false) => "☀️",
+            (WeatherCondition::Clear, true) => "🌙",
+            (WeatherCondition::FewClouds, false) => "🌤️",
+            (WeatherCondition::FewClouds, true) => "🌙",
+            (WeatherCondition::ScatteredClouds, false) => "⛅",
+            (WeatherCondition::ScatteredClouds, true) => "☁️",
+            (WeatherCondition::BrokenClouds, false) => "🌥️",
+            (WeatherCondition::BrokenClouds, true) => "☁️",
+            (WeatherCondition::Overcast, _) => "☁️",
+            (WeatherCondition::Unknown, _) => "🌤️",
+        }
+    }
+
+    /// Returns the locale key of the condition name.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            WeatherCondition::Thunderstorm => "condition-thunderstorm",
+            WeatherCondition::Drizzle => "condition-drizzle",
+            WeatherCondition::Rain => "condition-rain",
+            WeatherCondition::FreezingRain => "condition-freezing-rain",
+            WeatherCondition::ShowerRain => "condition-shower-rain",
+            WeatherCondition::Snow => "condition-snow",
+            WeatherCondition::Sleet => "condition-sleet",
+            WeatherCondition::ShowerSnow => "condition-shower-snow",
+            WeatherCondition::Mist => "condition-mist",
+            WeatherCondition::Smoke => "condition-smoke",
+            WeatherCondition::Haze => "condition-haze",
+            WeatherCondition::Dust => "condition-dust",
+            WeatherCondition::Fog => "condition-fog",
+            WeatherCondition::Ash => "condition-ash",
+            WeatherCondition::Squall => "condition-squall",
+            WeatherCondition::Tornado => "condition-tornado",
+            WeatherCondition::Clear => "condition-clear",
+            WeatherCondition::FewClouds => "condition-few-clouds",
+            WeatherCondition::ScatteredClouds => "condition-scattered-clouds",
+            WeatherCondition::BrokenClouds => "condition-broken-clouds",
+            WeatherCondition::Overcast => "condition-overcast",
+            WeatherCondition::Unknown => "condition-unknown",
+        }
+    }
+}
@@ -1,72 +1,187 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use chrono::Locale;
+use fluent_bundle::{FluentBundle, FluentResource};
 use unic_langid::LanguageIdentifier;
 
-use crate::enums::Callbacks;
+/// Directory scanned for `<code>.ftl` bundles, relative to the working directory.
+const LOCALES_DIR: &str = "locales";
+
+/// Language of users whose Telegram client language is not supported.
+const DEFAULT_LANGUAGE: &str = "uk";
+
+/// Prefix of the callback data of the language selection buttons.
+const CALLBACK_PREFIX: &str = "language-";
+
+/// Metadata of a bundle in [`LOCALES_DIR`], read from its `language-*` messages.
+struct LanguageInfo {
+    /// File name without extension, also stored in the database.
+    code: String,
+    path: String,
+    lang_id: LanguageIdentifier,
+    /// Native name, `language-name`.
+    name: String,
+    /// Emoji flag, `language-flag`.
+    flag: String,
+    /// Language code sent to the weather provider, `language-provider-code`.
+    provider_code: String,
+    /// POSIX locale with number and date conventions, `language-locale`.
+    locale: Locale,
+    callback: String,
+}
+
+/// All supported languages, ordered by code.
+///
+/// Loaded on first use, a missing or broken bundle stops the bot.
+static LANGUAGES: LazyLock<Vec<LanguageInfo>> = LazyLock::new(|| {
+    discover(Path::new(LOCALES_DIR))
+        .unwrap_or_else(|e| panic!("cannot load languages from {LOCALES_DIR}/: {e}"))
+});
+
+/// Reads the metadata of every `.ftl` file in `dir`.
+///
+/// # Returns
+/// - `Ok(Vec<LanguageInfo>)` sorted by code
+/// - `Err` if a file cannot be read or parsed, its name is not a language code,
+///   or a `language-*` message is missing or invalid
+fn discover(dir: &Path) -> Result<Vec<LanguageInfo>, Box<dyn Error + Send + Sync>> {
+    let mut languages = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("ftl") {
+            continue;
+        }
+
+        let code = path.file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| format!("{} has no valid file name", path.display()))?
+            .to_string();
+        let lang_id = code.parse::<LanguageIdentifier>()
+            .map_err(|e| format!("{} is not named after a language code: {e}", path.display()))?;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Languages {
-    En,
-    Uk,
-    De,
+        let resource = FluentResource::try_new(fs::read_to_string(&path)?)
+            .map_err(|(_, errors)| format!("cannot parse {}: {errors:?}", path.display()))?;
+        let mut bundle = FluentBundle::new(vec![lang_id.clone()]);
+        bundle.set_use_isolating(false);
+        bundle.add_resource(resource)
+            .map_err(|errors| format!("cannot load {}: {errors:?}", path.display()))?;
+
+        let message = |key: &str| {
+            bundle.get_message(key)
+                .and_then(|m| m.value())
+                .map(|pattern| bundle.format_pattern(pattern, None, &mut vec![]).trim().to_string())
+                .ok_or_else(|| format!("{} has no {key} message", path.display()))
+        };
+
+        languages.push(LanguageInfo {
+            name: message("language-name")?,
+            flag: message("language-flag")?,
+            provider_code: message("language-provider-code")?,
+            locale: Locale::try_from(message("language-locale")?.as_str())
+                .map_err(|_| format!("{} has an unknown language-locale", path.display()))?,
+            callback: format!("{CALLBACK_PREFIX}{code}"),
+            path: path.to_string_lossy().into_owned(),
+            lang_id,
+            code,
+        });
+    }
+
+    if languages.is_empty() {
+        return Err("no .ftl files found".into());
+    }
+
+    languages.sort_by(|a, b| a.code.cmp(&b.code));
+    Ok(languages)
+}
+
+/// A language with a bundle in `locales/`.
+///
+/// Adding a language only needs a new `<code>.ftl` file with all messages, including
+/// `language-name`, `language-flag`, `language-provider-code` and `language-locale`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Languages(&'static str);
+
+impl Default for Languages {
+    fn default() -> Self {
+        Self::from_str(DEFAULT_LANGUAGE)
+            .unwrap_or_else(|| panic!("{LOCALES_DIR}/{DEFAULT_LANGUAGE}.ftl not found"))
+    }
 }
 
 impl Languages {
+    fn info(&self) -> &'static LanguageInfo {
+        // Values are only created from `LANGUAGES`, so the lookup cannot fail
+        LANGUAGES.iter()
+            .find(|l| l.code == self.0)
+            .expect("language is not loaded")
+    }
+
     pub fn as_str(&self) -> &'static str {
-        match self {
-            Languages::En => "en",
-            Languages::Uk => "uk",
-            Languages::De => "de",
-        }
+        self.0
     }
 
     pub fn from_str(s: &str) -> Option<Self> {
-        match s {
-            "en" => Some(Languages::En),
-            "uk" => Some(Languages::Uk),
-            "de" => Some(Languages::De),
-            &_ => None,
-        }
+        LANGUAGES.iter()
+            .find(|l| l.code == s)
+            .map(|l| Languages(l.code.as_str()))
+    }
+
+    /// Picks the language for a Telegram `language_code` such as `de-AT`.
+    ///
+    /// Only the primary subtag is compared, so regional variants get their base language.
+    /// Unsupported languages are looked up in `fallbacks` (keyed by primary subtag),
+    /// everything else gets [`Languages::default`].
+    pub fn detect(language_code: Option<&str>, fallbacks: &HashMap<String, Languages>) -> Self {
+        let Some(primary) = language_code
+            .and_then(|code| code.split(['-', '_']).next())
+            .map(str::to_lowercase)
+        else {
+            return Self::default();
+        };
+
+        Self::from_str(&primary)
+            .or_else(|| fallbacks.get(&primary).copied())
+            .unwrap_or_default()
     }
 
     pub fn lang_id(&self) -> LanguageIdentifier {
-        match self {
-            Languages::Uk => "uk".parse().unwrap(),
-            Languages::En => "en".parse().unwrap(),
-            Languages::De => "de".parse().unwrap(),
-        }
+        self.info().lang_id.clone()
     }
 
     pub fn path(&self) -> &'static str {
-        match self {
-            Languages::Uk => "locales/uk.ftl",
-            Languages::En => "locales/en.ftl",
-            Languages::De => "locales/de.ftl",
-        }
+        &self.info().path
     }
 
-    pub fn label(&self) -> &'static str {
-        match self {
-            Languages::Uk => "🇺🇦 | Українська",
-            Languages::En => "🇺🇸 | English",
-            Languages::De => "🇩🇪 | Deutsch"
-        }
+    pub fn label(&self) -> String {
+        format!("{} | {}", self.info().flag, self.info().name)
     }
 
-    pub fn callback(&self) -> Callbacks {
-        match self {
-            Languages::Uk => Callbacks::Ukrainian,
-            Languages::En => Callbacks::English,
-            Languages::De => Callbacks::Deutsch,
+    /// Language code sent to the weather provider for localized city names and descriptions.
+    pub fn provider_code(&self) -> &'static str {
+        &self.info().provider_code
+    }
 
-        }
+    /// Locale used to format numbers and dates in messages.
+    pub fn locale(&self) -> Locale {
+        self.info().locale
+    }
+
+    /// Callback data of the button selecting this language.
+    pub fn callback_data(&self) -> &'static str {
+        &self.info().callback
     }
-    
-    pub fn all() -> &'static [Languages] {
-        &[Languages::Uk, Languages::En, Languages::De]
+
+    /// Parses the callback data of a language selection button.
+    pub fn from_callback_data(data: &str) -> Option<Self> {
+        data.strip_prefix(CALLBACK_PREFIX).and_then(Self::from_str)
     }
-}
 
-impl Default for Languages {
-    fn default() -> Self {
-        Languages::Uk
+    pub fn all() -> impl Iterator<Item = Languages> {
+        LANGUAGES.iter().map(|l| Languages(l.code.as_str()))
     }
 }
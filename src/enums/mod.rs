@@ -2,6 +2,9 @@ pub mod commands;
 pub mod callbacks;
 pub mod languages;
 pub mod units;
+pub mod conditions;
+pub mod air_quality;
+pub mod uv;
 
-pub use commands::Commands;
+pub use commands::{AdminCommands, Commands};
 pub use callbacks::Callbacks;
@@ -1,5 +1,6 @@
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
 pub enum SpeedUnits {
+    #[default]
     KilometersPerHour,
     MetersPerSecond,
     MilesPerHour,
@@ -26,9 +27,3 @@ impl SpeedUnits {
         }
     }
 }
-
-impl Default for SpeedUnits {
-    fn default() -> Self {
-        SpeedUnits::KilometersPerHour
-    }
-}
@@ -1,5 +1,6 @@
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
 pub enum TemperatureUnits {
+    #[default]
     Celsius,
     Fahrenheit,
     Kelvin,
@@ -14,6 +15,15 @@ impl TemperatureUnits {
         }
     }
 
+    /// Returns the unit symbol for display (e.g. `°C`, `K`).
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureUnits::Celsius => "°C",
+            TemperatureUnits::Fahrenheit => "°F",
+            TemperatureUnits::Kelvin => "K",
+        }
+    }
+
     pub fn from_str(unit: &str) -> Option<Self> {
         match unit {
             "C" => Some(TemperatureUnits::Celsius),
@@ -23,9 +33,3 @@ impl TemperatureUnits {
         }
     }
 }
-
-impl Default for TemperatureUnits {
-    fn default() -> Self {
-        TemperatureUnits::Celsius
-    }
-}
\ No newline at end of file
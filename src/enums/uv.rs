@@ -0,0 +1,59 @@
+/// UV index risk bands as defined by the WHO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UvRisk {
+    Low,
+    Moderate,
+    High,
+    VeryHigh,
+    Extreme,
+}
+
+impl UvRisk {
+    /// Maps a UV index value to the risk band.
+    pub fn from_index(index: f64) -> Self {
+        match index.round() as i64 {
+            i64::MIN..=2 => UvRisk::Low,
+            3..=5 => UvRisk::Moderate,
+            6..=7 => UvRisk::High,
+            8..=10 => UvRisk::VeryHigh,
+            _ => UvRisk::Extreme,
+        }
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            UvRisk::Low => "🟢",
+            UvRisk::Moderate => "🟡",
+            UvRisk::High => "🟠",
+            UvRisk::VeryHigh => "🔴",
+            UvRisk::Extreme => "🟣",
+        }
+    }
+
+    /// Returns `true` if users should be alerted about the UV level.
+    pub fn is_alert(&self) -> bool {
+        matches!(self, UvRisk::High | UvRisk::VeryHigh | UvRisk::Extreme)
+    }
+
+    /// Returns the locale key of the band name.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            UvRisk::Low => "uv-low",
+            UvRisk::Moderate => "uv-moderate",
+            UvRisk::High => "uv-high",
+            UvRisk::VeryHigh => "uv-very-high",
+            UvRisk::Extreme => "uv-extreme",
+        }
+    }
+
+    /// Returns the locale key of the sun protection advice.
+    pub fn advice_key(&self) -> &'static str {
+        match self {
+            UvRisk::Low => "uv-advice-low",
+            UvRisk::Moderate => "uv-advice-moderate",
+            UvRisk::High => "uv-advice-high",
+            UvRisk::VeryHigh => "uv-advice-very-high",
+            UvRisk::Extreme => "uv-advice-extreme",
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use fluent_bundle::FluentArgs;
+use teloxide::prelude::*;
+use teloxide::types::{MessageId, ParseMode};
+use teloxide::utils::html;
+
+use crate::config::Config;
+use crate::db::Repository;
+use crate::delivery::{Delivered, Delivery};
+use crate::enums::languages::Languages;
+use crate::fluent_args;
+use crate::logging::error_chain;
+use crate::types::HandlerResult;
+use crate::utils::locales::get_text;
+
+/// Period for counting active users in `/stats`.
+const ACTIVE_DAYS: i64 = 7;
+
+/// How often the broadcast progress message is updated.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Returns `true` if the message was sent by a configured admin.
+///
+/// Used as a filter in the update schema, so admin commands from other users are ignored.
+pub fn is_admin(msg: Message, config: Arc<Config>) -> bool {
+    msg.from
+        .as_ref()
+        .is_some_and(|user| config.is_admin(user.id.0 as i64))
+}
+
+/// Returns the language stored for the admin, or the default one.
+async fn admin_language(db: &Repository, msg: &Message) -> Languages {
+    let Some(user) = msg.from.as_ref() else {
+        return Languages::default();
+    };
+
+    db.get_user(user.id.0 as i64)
+        .await
+        .and_then(|u| Languages::from_str(&u.language))
+        .unwrap_or_default()
+}
+
+/// Formats `(value, count)` pairs as `value — count` lines.
+fn format_counts(counts: &[(String, i64)]) -> String {
+    counts
+        .iter()
+        .map(|(value, count)| format!("{} — {count}", html::escape(value)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Handles `/stats`: shows user counts by kind, language and units.
+pub async fn stats_handler(bot: Bot, msg: Message, db: Repository) -> HandlerResult {
+    let lang = admin_language(&db, &msg).await;
+
+    let text = match db.stats(ACTIVE_DAYS).await {
+        Ok(stats) => get_text(lang, "admin-stats", Some(&fluent_args![
+            "users" => stats.users,
+            "groups" => stats.groups,
+            "active" => stats.active,
+            "inactive" => stats.inactive,
+            "days" => ACTIVE_DAYS,
+            "languages" => format_counts(&stats.languages),
+            "temperature" => format_counts(&stats.temperature_units),
+            "speed" => format_counts(&stats.speed_units),
+        ])),
+        Err(e) => {
+            tracing::error!(error = %error_chain(e.as_ref()), "Could not load user statistics");
+            get_text(lang, "error", None)
+        }
+    };
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
+
+/// Handles `/user <id>`: shows the stored record of a user or group.
+pub async fn user_handler(bot: Bot, msg: Message, db: Repository, id: String) -> HandlerResult {
+    let lang = admin_language(&db, &msg).await;
+
+    let text = match id.trim().parse::<i64>() {
+        Ok(id) => match db.get_user(id).await {
+            Some(user) => get_text(lang, "admin-user", Some(&fluent_args![
+                "id" => user.id.to_string(),
+                "city" => html::escape(&user.city),
+                "language" => user.language,
+                "temperature" => user.temperature_unit,
+                "speed" => user.speed_unit,
+                "created" => user.created_at.format("%Y-%m-%d %H:%M").to_string(),
+                "updated" => user.updated_at.format("%Y-%m-%d %H:%M").to_string(),
+                "active" => user.last_active_at.format("%Y-%m-%d %H:%M").to_string(),
+            ])),
+            None => get_text(lang, "admin-user-not-found", Some(&fluent_args!["id" => id.to_string()])),
+        },
+        Err(_) => get_text(lang, "admin-user-usage", None),
+    };
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
+
+/// Handles `/broadcast <text>`: sends the text to all active users and groups.
+///
+/// The text is sent as HTML through the rate-limited [`Delivery`] queue in a
+/// background task, and a progress message is updated along the way.
+pub async fn broadcast_handler(
+    bot: Bot,
+    msg: Message,
+    db: Repository,
+    delivery: Delivery,
+    text: String
+) -> HandlerResult {
+    let lang = admin_language(&db, &msg).await;
+
+    if text.trim().is_empty() {
+        bot.send_message(msg.chat.id, get_text(lang, "admin-broadcast-usage", None)).await?;
+        return Ok(());
+    }
+
+    let chat_ids = db.chat_ids().await?;
+    let total = chat_ids.len();
+    let progress = bot
+        .send_message(msg.chat.id, broadcast_progress(lang, &BroadcastProgress::default(), total, false))
+        .await?;
+
+    // Broadcasting takes a while, so it must not block other updates from the admin
+    tokio::spawn(async move {
+        let result = broadcast(&bot, &delivery, &chat_ids, &text, lang, msg.chat.id, progress.id).await;
+        tracing::info!(sent = result.sent, blocked = result.blocked, failed = result.failed, total, "Broadcast finished");
+    });
+
+    Ok(())
+}
+
+/// Message counts of a running broadcast.
+#[derive(Debug, Default)]
+struct BroadcastProgress {
+    sent: usize,
+    /// Chats that blocked the bot, they are skipped from now on
+    blocked: usize,
+    failed: usize,
+}
+
+/// Sends the broadcast and returns the final counts.
+async fn broadcast(
+    bot: &Bot,
+    delivery: &Delivery,
+    chat_ids: &[i64],
+    text: &str,
+    lang: Languages,
+    progress_chat: ChatId,
+    progress_message: MessageId,
+) -> BroadcastProgress {
+    let total = chat_ids.len();
+    let mut progress = BroadcastProgress::default();
+    let mut last_update = Instant::now();
+
+    for &chat_id in chat_ids {
+        match delivery.send_message(ChatId(chat_id), text).await {
+            Ok(Delivered::Sent(_)) => progress.sent += 1,
+            Ok(Delivered::Unreachable) => progress.blocked += 1,
+            Err(e) => {
+                tracing::warn!(chat_id, error = %error_chain(&e), "Broadcast message failed");
+                progress.failed += 1;
+            }
+        }
+
+        if last_update.elapsed() >= PROGRESS_INTERVAL {
+            last_update = Instant::now();
+            if let Err(e) = bot
+                .edit_message_text(progress_chat, progress_message, broadcast_progress(lang, &progress, total, false))
+                .await
+            {
+                tracing::debug!(error = %error_chain(&e), "Could not update broadcast progress");
+            }
+        }
+    }
+
+    if let Err(e) = bot
+        .edit_message_text(progress_chat, progress_message, broadcast_progress(lang, &progress, total, true))
+        .await
+    {
+        tracing::warn!(error = %error_chain(&e), "Could not update broadcast progress");
+    }
+
+    progress
+}
+
+/// Formats the broadcast progress message.
+fn broadcast_progress(lang: Languages, progress: &BroadcastProgress, total: usize, done: bool) -> String {
+    let key = if done { "admin-broadcast-done" } else { "admin-broadcast-progress" };
+    get_text(lang, key, Some(&fluent_args![
+        "sent" => progress.sent,
+        "blocked" => progress.blocked,
+        "failed" => progress.failed,
+        "total" => total,
+    ]))
+}
@@ -0,0 +1,110 @@
+use fluent_bundle::FluentArgs;
+use teloxide::prelude::*;
+use teloxide::types::{
+    InlineQuery, InlineQueryResult, InlineQueryResultArticle, InputMessageContent,
+    InputMessageContentText, ParseMode,
+};
+
+use crate::api::Provider;
+use crate::db::Repository;
+use crate::enums::languages::Languages;
+use crate::enums::units::TemperatureUnits;
+use crate::fluent_args;
+use crate::handlers::weather::{forecast_message, forecast_summary, WeatherPeriod};
+use crate::logging::error_chain;
+use crate::types::HandlerResult;
+use crate::utils::locales::get_text;
+use crate::utils::string::capitalize_first_letter;
+
+/// How long Telegram may cache inline results, in seconds.
+const CACHE_TIME: u32 = 300;
+
+/// Typed city names shorter than this are not looked up, so the first keystrokes
+/// of a name do not each cost a provider request.
+const MIN_QUERY_CHARS: usize = 3;
+
+/// Periods offered as inline result cards, in display order.
+const PERIODS: [WeatherPeriod; 3] = [WeatherPeriod::Now, WeatherPeriod::Today, WeatherPeriod::Tomorrow];
+
+/// Returns `true` if the query requests data from the weather provider.
+///
+/// An empty query uses the saved city, a typed city needs at least [`MIN_QUERY_CHARS`] characters.
+pub fn fetches_weather(query: &InlineQuery) -> bool {
+    let typed = query.query.trim();
+    typed.is_empty() || typed.chars().count() >= MIN_QUERY_CHARS
+}
+
+/// Handles inline queries (`@bot <city>`) from any chat.
+///
+/// - Uses the typed city, or the user's saved city if the query is empty.
+/// - Answers with no results while the typed city is too short, see [`fetches_weather`].
+/// - Formats result cards (now, today, tomorrow) in the user's stored language and units.
+/// - Answers with no results if the city is unknown or the provider fails.
+///
+/// # Arguments
+/// - `bot` - The Telegram bot instance.
+/// - `query` - Incoming inline query.
+/// - `db` - Shared user repository.
+/// - `provider` - Weather provider.
+pub async fn handler(bot: Bot, query: InlineQuery, db: Repository, provider: Provider) -> HandlerResult {
+    let user = db.get_user(query.from.id.0 as i64).await;
+
+    let lang = user
+        .as_ref()
+        .and_then(|u| Languages::from_str(&u.language))
+        .unwrap_or_default();
+    let temperature_unit = user
+        .as_ref()
+        .and_then(|u| TemperatureUnits::from_str(&u.temperature_unit))
+        .unwrap_or_default();
+
+    let city = match query.query.trim() {
+        "" => user.map(|u| u.city).unwrap_or_default(),
+        city => capitalize_first_letter(city),
+    };
+
+    let results = if city.is_empty() || !fetches_weather(&query) {
+        Vec::new()
+    }
+    else {
+        match provider.forecast(&city, lang).await {
+            Ok(response) => PERIODS
+                .iter()
+                .filter_map(|&period| {
+                    let forecast = period.selector()(&response)?;
+                    let text = forecast_message(&city, period, &response, lang, temperature_unit)?;
+                    // Titles are plain text, only the sent message is HTML
+                    let title = get_text(lang, "inline-title", Some(&fluent_args![
+                        "day" => period.label(lang),
+                        "city" => city.as_str(),
+                    ]));
+
+                    let content = InputMessageContent::Text(
+                        InputMessageContentText::new(text).parse_mode(ParseMode::Html)
+                    );
+                    let article = InlineQueryResultArticle::new(
+                        period.as_str(),
+                        title,
+                        content,
+                    )
+                        .description(forecast_summary(forecast, lang, temperature_unit));
+
+                    Some(InlineQueryResult::Article(article))
+                })
+                .collect(),
+            Err(e) => {
+                // Partially typed city names fail often, so this is not a warning
+                tracing::debug!(city, error = %error_chain(&e), "Inline forecast failed");
+                Vec::new()
+            }
+        }
+    };
+
+    // Results depend on the user's settings, so they must not be shared between users
+    bot.answer_inline_query(query.id, results)
+        .cache_time(CACHE_TIME)
+        .is_personal(true)
+        .await?;
+
+    Ok(())
+}
@@ -2,3 +2,7 @@ pub mod start;
 pub mod receive_city;
 pub mod weather;
 pub mod settings;
+pub mod inline;
+pub mod admin;
+pub mod throttled;
+pub mod privacy;
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use teloxide::prelude::*;
+use teloxide::types::{CallbackQuery, InputFile};
+
+use crate::activity::Activity;
+use crate::config::Config;
+use crate::db::{Repository, User};
+use crate::enums::languages::Languages;
+use crate::logging::error_chain;
+use crate::metrics;
+use crate::throttle::{InlineThrottle, Throttle};
+use crate::traits::chat::ChatSource;
+use crate::types::{HandlerResult, MyDialogue};
+use crate::utils::chat::send_or_edit;
+use crate::utils::keyboard::{get_delete_confirm_keyboard, get_to_hub};
+use crate::utils::locales::get_text;
+
+/// Document sent by `/export`.
+#[derive(Serialize)]
+struct Export<'a> {
+    exported_at: DateTime<Utc>,
+    user: &'a User,
+}
+
+/// Returns the language stored for the user, or the default one.
+async fn user_language(db: &Repository, user_id: i64) -> Languages {
+    db.get_user(user_id)
+        .await
+        .and_then(|u| Languages::from_str(&u.language))
+        .unwrap_or_default()
+}
+
+/// Handles `/deleteme`: asks the user to confirm deleting their data.
+///
+/// Only available in private chats, group settings belong to the whole group.
+pub async fn delete_command_handler(bot: Bot, msg: Message, db: Repository, config: Arc<Config>) -> HandlerResult {
+    let Some(user) = db.get_user(msg.user_id()).await.filter(|_| !msg.is_group()) else {
+        return reply_unavailable(&bot, &msg, &db, &config).await;
+    };
+
+    let lang = Languages::from_str(&user.language).unwrap_or_default();
+    bot.send_message(msg.chat.id, get_text(lang, "delete-confirm", None))
+        .reply_markup(get_delete_confirm_keyboard(lang))
+        .await?;
+
+    Ok(())
+}
+
+/// Handles the "Delete my data" button: removes the user's record and dialogue state.
+///
+/// The user ID is also dropped from everything kept in memory: the active users metric,
+/// the request throttles and the activity tracker.
+pub async fn delete_confirm_handler(
+    bot: Bot,
+    callback: CallbackQuery,
+    dialogue: MyDialogue,
+    db: Repository,
+    throttle: Arc<Throttle>,
+    inline_throttle: Arc<InlineThrottle>,
+    activity: Arc<Activity>,
+) -> HandlerResult {
+    let user_id = callback.user_id();
+    // The language is gone together with the record
+    let lang = user_language(&db, user_id).await;
+
+    let deleted = db.delete_user(user_id).await;
+    if deleted.is_ok() {
+        metrics::forget_active_user(callback.from.id.0);
+        throttle.forget(callback.from.id.0);
+        inline_throttle.forget(callback.from.id.0);
+        activity.forget(user_id);
+    }
+
+    let text = match deleted {
+        Ok(true) => {
+            dialogue.exit().await?;
+            tracing::info!(user_id, "User data deleted on request");
+            get_text(lang, "delete-done", None)
+        }
+        Ok(false) => get_text(lang, "privacy-no-data", None),
+        Err(e) => {
+            tracing::error!(error = %error_chain(e.as_ref()), "Could not delete user data");
+            get_text(lang, "error", None)
+        }
+    };
+
+    send_or_edit(&bot, &callback, ChatId(callback.chat_id()), &text, None).await?;
+    bot.answer_callback_query(callback.id).await?;
+
+    Ok(())
+}
+
+/// Handles the "Cancel" button of the deletion prompt.
+pub async fn delete_cancel_handler(bot: Bot, callback: CallbackQuery, db: Repository) -> HandlerResult {
+    let lang = user_language(&db, callback.user_id()).await;
+
+    let text = get_text(lang, "delete-cancelled", None);
+    send_or_edit(&bot, &callback, ChatId(callback.chat_id()), &text, Some(get_to_hub(lang))).await?;
+    bot.answer_callback_query(callback.id).await?;
+
+    Ok(())
+}
+
+/// Handles `/export`: sends everything stored about the user as a JSON document.
+///
+/// Only available in private chats, so the data is not shown to other group members.
+pub async fn export_handler(bot: Bot, msg: Message, db: Repository, config: Arc<Config>) -> HandlerResult {
+    let Some(user) = db.get_user(msg.user_id()).await.filter(|_| !msg.is_group()) else {
+        return reply_unavailable(&bot, &msg, &db, &config).await;
+    };
+
+    let lang = Languages::from_str(&user.language).unwrap_or_default();
+    let document = serde_json::to_vec_pretty(&Export { exported_at: Utc::now(), user: &user })?;
+
+    bot.send_document(msg.chat.id, InputFile::memory(document).file_name("weather-bot-data.json"))
+        .caption(get_text(lang, "export-caption", None))
+        .await?;
+
+    Ok(())
+}
+
+/// Explains why `/deleteme` or `/export` did nothing: a group chat, or no stored data.
+async fn reply_unavailable(bot: &Bot, msg: &Message, db: &Repository, config: &Config) -> HandlerResult {
+    let (lang, key) = if msg.is_group() {
+        (user_language(db, msg.settings_id()).await, "privacy-private-only")
+    } else {
+        (Languages::detect(msg.language_code(), &config.language_fallbacks), "privacy-no-data")
+    };
+
+    bot.send_message(msg.chat.id, get_text(lang, key, None)).await?;
+    Ok(())
+}
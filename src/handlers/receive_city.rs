@@ -1,38 +1,52 @@
+use std::sync::Arc;
+
 use teloxide::prelude::*;
 use teloxide::Bot;
 use teloxide::types::Message;
 
-use crate::db::pool::DbPool;
-use crate::db::queries::UserQueries;
+use crate::config::Config;
+use crate::db::Repository;
 use crate::enums::languages::Languages;
 use crate::handlers::start;
+use crate::logging::error_chain;
+use crate::traits::chat::ChatSource;
 use crate::types::{HandlerResult, MyDialogue};
+use crate::utils::chat::can_edit_settings;
 use crate::utils::locales::get_text;
 
 /// Handler receiving the user's city.
 ///
 /// Expects the user to send a text message containing their city name.
-/// Saves the city to the database and exits the dialogue
+/// Saves the city to the database and exits the dialogue. A new record gets
+/// the language of the sender's Telegram client.
+/// In group chats the city is saved for the whole group and only
+/// messages from administrators are accepted.
 ///
 /// # Arguments
 /// * `bot` - The Telegram bot instance.
 /// * `dialogue` - Dialogue state for the user.
 /// * `msg` - Incoming message containing the city.
 /// * `db` - Database connection wrapper.
-pub async fn handler(bot: Bot, dialogue: MyDialogue, msg: Message, db: DbPool) -> HandlerResult {
-    let user_id = if let Some(user) = &msg.from {
-        user.id.0 as i64
-    }
-    else {
+/// * `config` - Configuration with the language fallbacks.
+pub async fn handler(
+    bot: Bot,
+    dialogue: MyDialogue,
+    msg: Message,
+    db: Repository,
+    config: Arc<Config>,
+) -> HandlerResult {
+    if msg.from.is_none() || !can_edit_settings(&bot, &msg).await {
         return Ok(());
-    };
+    }
+
+    let user_id = msg.settings_id();
 
-    let user = UserQueries::get_user(&db, user_id).await;
+    let user = db.get_user(user_id).await;
 
     let lang = user
         .as_ref()
         .and_then(|u| Languages::from_str(&u.language))
-        .unwrap_or_default();
+        .unwrap_or_else(|| Languages::detect(msg.language_code(), &config.language_fallbacks));
 
     let city = match msg.text().filter(|c| !c.trim().is_empty()) {
         Some(city) => city,
@@ -42,13 +56,14 @@ pub async fn handler(bot: Bot, dialogue: MyDialogue, msg: Message, db: DbPool) -
         }
     };
 
-    match UserQueries::upsert_city(&db, user_id, &city).await {
+    match db.upsert_city(user_id, city, lang.as_str()).await {
         Ok(_) => {
             bot.send_message(msg.chat.id, get_text(lang, "save-city-success", None)).await?;
-            let _ = dialogue.exit().await?;
-            start::message_handler(bot, msg, dialogue, db).await?;
+            dialogue.exit().await?;
+            start::message_handler(bot, msg, dialogue, db, config).await?;
         }
-        Err(_) => {
+        Err(e) => {
+            tracing::error!(error = %error_chain(e.as_ref()), "Could not save city");
             bot.send_message(msg.chat.id, get_text(lang, "saving-error", None)).await?;
         }
     }
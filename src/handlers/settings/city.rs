@@ -0,0 +1,46 @@
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+
+use crate::db::Repository;
+use crate::enums::languages::Languages;
+use crate::states::State;
+use crate::traits::chat::ChatSource;
+use crate::types::{HandlerResult, MyDialogue};
+use crate::utils::chat::{can_edit_settings, send_or_edit};
+use crate::utils::locales::get_text;
+
+/// Handles the "Change city" button.
+///
+/// Asks for the new city name and waits for it in the [`State::ReceiveCity`] dialogue state.
+/// In group chats only administrators may change the city.
+///
+/// # Arguments
+/// - `bot` - Telegram bot instance.
+/// - `callback` - Callback query from the user.
+/// - `dialogue` - Dialogue state manager.
+/// - `db` - User repository.
+///
+/// # Returns
+/// `HandlerResult`
+pub async fn handler(bot: Bot, callback: CallbackQuery, dialogue: MyDialogue, db: Repository) -> HandlerResult {
+    let lang = db.get_user(callback.settings_id())
+        .await
+        .and_then(|u| Languages::from_str(&u.language))
+        .unwrap_or_default();
+
+    if !can_edit_settings(&bot, &callback).await {
+        bot.answer_callback_query(callback.id)
+            .text(get_text(lang, "group-admin-only", None))
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    let key = if callback.is_group() { "change-city-group" } else { "change-city" };
+    send_or_edit(&bot, &callback, ChatId(callback.chat_id()), &get_text(lang, key, None), None).await?;
+    dialogue.update(State::ReceiveCity).await?;
+
+    bot.answer_callback_query(callback.id).await?;
+
+    Ok(())
+}
@@ -1,6 +1,3 @@
 pub mod hub;
 pub mod select;
 
-pub use select::ukrainian_handler;
-pub use select::english_handler;
-pub use select::deutsch_handler;
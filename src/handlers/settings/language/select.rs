@@ -1,38 +1,46 @@
 use teloxide::prelude::*;
 use teloxide::types::CallbackQuery;
 
-use crate::db::pool::DbPool;
-use crate::db::queries::UserQueries;
-use crate::enums::Callbacks;
+use crate::db::Repository;
 use crate::enums::languages::Languages;
+use crate::logging::error_chain;
 use crate::traits::chat::ChatSource;
 use crate::types::HandlerResult;
+use crate::utils::chat::can_edit_settings;
 use crate::utils::locales::get_text;
 
 /// Handles a language change request.
 /// Updates the user's language in the database if needed and shows a response alert.
+/// In group chats only administrators may change the language.
 ///
 /// # Arguments
 /// - `bot` - Telegram bot instance.
 /// - `callback` - Callback query from the user.
-/// - `pool` - Database connection pool.
-/// - `lang` - Target language.
+/// - `db` - User settings storage.
+/// - `lang` - Target language, parsed from the button's callback data.
 ///
 /// # Returns
 /// `HandlerResult`
-async fn handler(bot: Bot, callback: CallbackQuery, pool: DbPool, lang: Languages) -> HandlerResult {
-    let user_id = callback.user_id();
+pub async fn handler(bot: Bot, callback: CallbackQuery, db: Repository, lang: Languages) -> HandlerResult {
+    let user_id = callback.settings_id();
     let callback_id = callback.id.clone();
 
-    let text = match UserQueries::get_user(&pool, user_id).await {
+    let text = match db.get_user(user_id).await {
         Some(user) => {
-            if Languages::from_str(&user.language.as_str()) == Some(lang) {
+            if !can_edit_settings(&bot, &callback).await {
+                let current = Languages::from_str(&user.language).unwrap_or_default();
+                get_text(current, "group-admin-only", None)
+            }
+            else if Languages::from_str(user.language.as_str()) == Some(lang) {
                 get_text(lang, "language-no-change", None)
             }
             else {
-                match UserQueries::set_lang(&pool, user_id, lang.as_str()).await {
+                match db.set_lang(user_id, lang.as_str()).await {
                     Ok(_) => get_text(lang, "language-success", None),
-                    Err(_) => get_text(lang, "error", None),
+                    Err(e) => {
+                        tracing::error!(error = %error_chain(e.as_ref()), "Could not save language");
+                        get_text(lang, "error", None)
+                    }
                 }
             }
         }
@@ -46,18 +54,3 @@ async fn handler(bot: Bot, callback: CallbackQuery, pool: DbPool, lang: Language
 
     Ok(())
 }
-
-/// Sets the user's language to Ukrainian.
-pub async fn ukrainian_handler(bot: Bot, callback: CallbackQuery, db: DbPool) -> HandlerResult {
-    handler(bot, callback, db, Languages::Uk).await
-}
-
-/// Sets the user's language to English.
-pub async fn english_handler(bot: Bot, callback: CallbackQuery, db: DbPool) -> HandlerResult {
-    handler(bot, callback, db, Languages::En).await
-}
-
-/// Sets the user's language to Deutsch.
-pub async fn deutsch_handler(bot: Bot, callback: CallbackQuery, db: DbPool) -> HandlerResult {
-    handler(bot, callback, db, Languages::De).await
-}
\ No newline at end of file
@@ -1,3 +1,5 @@
+pub mod city;
 pub mod hub;
 pub mod language;
 pub mod units;
+pub mod uv_alerts;
@@ -0,0 +1,53 @@
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+
+use crate::db::Repository;
+use crate::enums::languages::Languages;
+use crate::logging::error_chain;
+use crate::traits::chat::ChatSource;
+use crate::types::HandlerResult;
+use crate::utils::chat::can_edit_settings;
+use crate::utils::locales::get_text;
+
+/// Turns the high UV index alert on or off.
+///
+/// # Arguments
+/// - `bot` - The Telegram bot instance.
+/// - `callback` - The callback query triggered for user.
+/// - `db` - Shared user repository.
+///
+/// # Behavior
+/// - In group chats, refuses the change unless the user is an administrator.
+/// - Otherwise, flips the setting and replies with a callback alert showing the new state.
+pub async fn handler(bot: Bot, callback: CallbackQuery, db: Repository) -> HandlerResult {
+    let user_id = callback.settings_id();
+    let callback_id = callback.id.clone();
+
+    let text = match db.get_user(user_id).await {
+        Some(user) => {
+            let lang = Languages::from_str(&user.language).unwrap_or_default();
+
+            if !can_edit_settings(&bot, &callback).await {
+                get_text(lang, "group-admin-only", None)
+            }
+            else {
+                match db.set_uv_alerts(user_id, !user.uv_alerts).await {
+                    Ok(_) if user.uv_alerts => get_text(lang, "uv-alerts-disabled", None),
+                    Ok(_) => get_text(lang, "uv-alerts-enabled", None),
+                    Err(e) => {
+                        tracing::error!(error = %error_chain(e.as_ref()), "Could not save UV alert setting");
+                        get_text(lang, "error", None)
+                    }
+                }
+            }
+        }
+        None => get_text(Languages::default(), "error", None),
+    };
+
+    bot.answer_callback_query(callback_id)
+        .text(text)
+        .show_alert(true)
+        .await?;
+
+    Ok(())
+}
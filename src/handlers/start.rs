@@ -1,9 +1,12 @@
 use fluent_bundle::FluentArgs;
+use std::sync::Arc;
+
 use teloxide::prelude::*;
 use teloxide::types::CallbackQuery;
+use teloxide::utils::html;
 
-use crate::db::pool::DbPool;
-use crate::db::queries::UserQueries;
+use crate::config::Config;
+use crate::db::Repository;
 use crate::enums::languages::Languages;
 use crate::states::State;
 use crate::traits::chat::ChatSource;
@@ -15,14 +18,18 @@ use crate::utils::locales::get_text;
 /// Universal handler for both messages and callback queries.
 ///
 /// - If the user exists in the database -> displays the saved city.
-/// - If the user does not exist -> asks the user to enter their city and updates the dialogue state.
+/// - If the user does not exist -> asks the user to enter their city in the language
+///   of their Telegram client and updates the dialogue state.
+///
+/// In group chats the group's own settings are used.
 ///
 /// # Arguments
 ///
 /// - `bot` - The bot instance.
 /// - `source` - The update source (message or callback query).
 /// - `dialogue` - Dialogue state manager.
-/// - `db` - Shared database connection.
+/// - `db` - Shared user repository.
+/// - `config` - Configuration with the language fallbacks.
 ///
 /// # Returns
 ///
@@ -31,22 +38,23 @@ async fn handler<T>(
         bot: Bot,
         source: T,
         dialogue: MyDialogue,
-        db: DbPool
+        db: Repository,
+        config: Arc<Config>,
 ) -> HandlerResult
 where
     T: ChatSource
 {
-    let user_id = source.user_id();
+    let user_id = source.settings_id();
     let chat_id = ChatId(source.chat_id());
 
-    let user = UserQueries::get_user(&db, user_id).await;
+    let user = db.get_user(user_id).await;
 
     match user {
         Some(user) => {
             let mut args = FluentArgs::new();
-            args.set("city", user.city);
+            args.set("city", html::escape(&user.city));
             let lang = Languages::from_str(&user.language)
-                .unwrap_or(Languages::default());
+                .unwrap_or_default();
             let text = get_text(lang, "hub-message", Some(&args));
 
             let keyboard = get_hub_keyboard(
@@ -55,7 +63,9 @@ where
             send_or_edit(&bot, &source, chat_id, &text, Some(keyboard)).await?;
         }
         None => {
-            let text = get_text(Languages::default(), "start", None);
+            let key = if source.is_group() { "start-group" } else { "start" };
+            let lang = Languages::detect(source.language_code(), &config.language_fallbacks);
+            let text = get_text(lang, key, None);
             send_or_edit(&bot, &source, chat_id, &text, None).await?;
             dialogue.update(State::ReceiveCity).await?;
         }
@@ -65,11 +75,23 @@ where
 }
 
 /// Handles /start message from users.
-pub async fn message_handler(bot: Bot, msg: Message, dialogue: MyDialogue, db: DbPool) -> HandlerResult {
-    handler(bot, msg, dialogue, db).await
+pub async fn message_handler(
+    bot: Bot,
+    msg: Message,
+    dialogue: MyDialogue,
+    db: Repository,
+    config: Arc<Config>,
+) -> HandlerResult {
+    handler(bot, msg, dialogue, db, config).await
 }
 
 /// Handles "Start" button callbacks from inline keyboards.
-pub async fn callback_handler(bot: Bot, callback: CallbackQuery, dialogue: MyDialogue, db: DbPool) -> HandlerResult {
-    handler(bot, callback, dialogue, db).await
+pub async fn callback_handler(
+    bot: Bot,
+    callback: CallbackQuery,
+    dialogue: MyDialogue,
+    db: Repository,
+    config: Arc<Config>,
+) -> HandlerResult {
+    handler(bot, callback, dialogue, db, config).await
 }
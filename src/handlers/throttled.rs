@@ -0,0 +1,69 @@
+use fluent_bundle::FluentArgs;
+use teloxide::prelude::*;
+use teloxide::types::{InlineQuery, InlineQueryResult};
+
+use crate::db::Repository;
+use crate::enums::languages::Languages;
+use crate::fluent_args;
+use crate::throttle::Throttled;
+use crate::traits::chat::ChatSource;
+use crate::types::HandlerResult;
+use crate::utils::locales::get_text;
+
+/// Answers a callback of a user who is over the request limit with a "please wait" alert.
+///
+/// # Arguments
+/// - `bot` - The Telegram bot instance.
+/// - `callback` - The throttled callback query.
+/// - `db` - Shared user repository.
+/// - `throttled` - Time until the user may try again.
+pub async fn callback_handler(bot: Bot, callback: CallbackQuery, db: Repository, throttled: Throttled) -> HandlerResult {
+    let lang = db.get_user(callback.settings_id())
+        .await
+        .and_then(|u| Languages::from_str(&u.language))
+        .unwrap_or_default();
+
+    bot.answer_callback_query(callback.id)
+        .text(wait_text(lang, throttled))
+        .show_alert(true)
+        .await?;
+
+    Ok(())
+}
+
+/// Answers a `/weather` command of a user who is over the request limit with a "please wait" message.
+///
+/// # Arguments
+/// - `bot` - The Telegram bot instance.
+/// - `msg` - The throttled command message.
+/// - `db` - Shared user repository.
+/// - `throttled` - Time until the user may try again.
+pub async fn message_handler(bot: Bot, msg: Message, db: Repository, throttled: Throttled) -> HandlerResult {
+    let lang = db.get_user(msg.settings_id())
+        .await
+        .and_then(|u| Languages::from_str(&u.language))
+        .unwrap_or_default();
+
+    bot.send_message(msg.chat.id, wait_text(lang, throttled)).await?;
+
+    Ok(())
+}
+
+/// Answers an inline query of a user who is over the request limit with no results.
+///
+/// Inline answers cannot show an alert, and the results are not cached so the
+/// next keystroke after the wait is answered normally.
+pub async fn inline_handler(bot: Bot, query: InlineQuery) -> HandlerResult {
+    bot.answer_inline_query(query.id, Vec::<InlineQueryResult>::new())
+        .cache_time(0)
+        .is_personal(true)
+        .await?;
+
+    Ok(())
+}
+
+/// Returns the localized "please wait" text.
+fn wait_text(lang: Languages, throttled: Throttled) -> String {
+    let seconds = throttled.retry_in.as_secs_f64().ceil().max(1.0) as u64;
+    get_text(lang, "throttled", Some(&fluent_args!["seconds" => seconds]))
+}
@@ -1,35 +1,41 @@
-use std::env;
-use dotenvy::dotenv;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, NaiveDateTime, Timelike, Utc};
 use fluent_bundle::FluentArgs;
 use teloxide::prelude::*;
 use teloxide::Bot;
-use teloxide::types::ParseMode;
-use once_cell::sync::Lazy;
+use teloxide::types::{InlineKeyboardMarkup, InputFile, ParseMode};
+use teloxide::utils::html;
+use teloxide::RequestError;
 
-use crate::db::pool::DbPool;
+use crate::config::Config;
+use crate::db::{Repository, User};
 use crate::types::HandlerResult;
-use crate::api::{fetch_forecast, today_weather, tomorrow_weather};
-use crate::api::models::{WeatherResponse, Forecast};
-use crate::db::queries::UserQueries;
+use crate::api::{current_weather, sun_times, today_weather, tomorrow_weather, Provider, SunTimes};
+use crate::api::models::{AirQuality, WeatherResponse, Forecast};
+use crate::api::provider::ProviderError;
+use crate::enums::conditions::WeatherCondition;
 use crate::enums::languages::Languages;
 use crate::enums::units::TemperatureUnits;
+use crate::enums::uv::UvRisk;
 use crate::fluent_args;
+use crate::logging::error_chain;
+use crate::metrics;
 use crate::traits::chat::ChatSource;
-use crate::utils::keyboard::get_to_hub;
+use crate::utils::chart::{render_forecast_chart, ChartPoint};
+use crate::utils::intl::FluentDateTime;
+use crate::utils::keyboard::{get_city_not_found_keyboard, get_to_hub};
 use crate::utils::locales::get_text;
 use crate::utils::string::capitalize_first_letter;
 
-static WEATHER_CONFIG: Lazy<Result<String, String>> = Lazy::new(|| {
-    dotenv().ok();
-    env::var("WEATHER_API_KEY").map_err(|_| {
-        eprintln!("WEATHER_API_KEY environment variable not set");
-        String::from("Missing API key")
-    })
-});
+/// Number of 3-hour forecast slots covering the next 48 hours on the chart.
+const CHART_SLOTS: usize = 17;
 
 /// Weather handler type, representing available forecast options.
 #[derive(Debug, Clone, Copy)]
-enum WeatherPeriod {
+pub enum WeatherPeriod {
+    /// Nearest forecast slot.
+    Now,
     /// Forecast for today.
     Today,
     /// Forecast for tomorrow.
@@ -37,75 +43,163 @@ enum WeatherPeriod {
 }
 
 impl WeatherPeriod {
-    /// Returns localized label for the forecast option.
-    fn label(&self, lang: Languages) -> String {
-        let key = match self {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WeatherPeriod::Now => "now",
             WeatherPeriod::Today => "today",
             WeatherPeriod::Tomorrow => "tomorrow",
-        };
-        get_text(lang, key, None)
+        }
+    }
+
+    /// Returns localized label for the forecast option.
+    pub fn label(&self, lang: Languages) -> String {
+        get_text(lang, self.as_str(), None)
     }
 
     /// Returns a selector function that extracts the right forecast
-    const fn selector(&self) -> fn(&WeatherResponse) -> Option<&Forecast> {
+    pub const fn selector(&self) -> fn(&WeatherResponse) -> Option<&Forecast> {
         match self {
+            WeatherPeriod::Now => current_weather,
             WeatherPeriod::Today => today_weather,
             WeatherPeriod::Tomorrow => tomorrow_weather
         }
     }
 }
 
+/// Weather view requested from the hub.
+#[derive(Debug, Clone, Copy)]
+enum WeatherView {
+    /// Forecast for the given period.
+    Forecast(WeatherPeriod),
+    /// Sunrise, sunset and day length.
+    Sun,
+    /// Air quality index and pollutants.
+    AirQuality,
+    /// Temperature and precipitation chart image.
+    Chart,
+}
+
 /// Errors that can occur during weather handler
 #[derive(Debug)]
 enum WeatherError {
-    /// API key not found in environment
-    MissingApiKey,
-
     /// User not found in database
     UserNotFound,
 
-    /// Failed to fetch weather data
+    /// Weather provider does not know the city
+    CityNotFound,
+
+    /// Weather provider rejected the API key
+    InvalidApiKey,
+
+    /// Request quota of the weather provider is exhausted
+    QuotaExceeded,
+
+    /// Weather provider did not respond in time
+    Timeout,
+
+    /// Weather provider returned a response that could not be decoded
+    InvalidResponse,
+
+    /// Weather provider is unavailable or failed for another reason
     ApiFetchError,
 
+    /// Failed to send or edit the Telegram message
+    TelegramError,
+
     /// No forecast data available for requested period
     NoForecastData,
 
     /// Missing message in callback query
-    MissingMessage
+    MissingMessage,
+
+    /// Failed to encode the chart image
+    ChartError
 }
 
 impl WeatherError {
-    /// Returns user-friendly error message
-    fn user_message(&self, lang: Languages) -> String {
-        let key = match self {
-            WeatherError::MissingApiKey => "service-error",
+    /// Returns the locale key of the error message, also used as the metrics label
+    fn locale_key(&self) -> &'static str {
+        match self {
             WeatherError::UserNotFound => "user-not-found",
+            WeatherError::CityNotFound => "weather-city-not-found",
+            WeatherError::InvalidApiKey => "weather-invalid-api-key",
+            WeatherError::QuotaExceeded => "weather-quota-exceeded",
+            WeatherError::Timeout => "weather-timeout",
+            WeatherError::InvalidResponse => "weather-invalid-response",
             WeatherError::ApiFetchError => "api-fetch-error",
+            WeatherError::TelegramError => "telegram-error",
             WeatherError::NoForecastData => "no-forecast-data",
             WeatherError::MissingMessage => "missing-message",
-        };
-        get_text(lang, key, None)
+            WeatherError::ChartError => "error",
+        }
+    }
+
+    /// Logs a failed weather provider request and classifies it
+    fn from_provider(e: ProviderError) -> Self {
+        match e {
+            ProviderError::CityNotFound => WeatherError::CityNotFound,
+            ProviderError::NoData => WeatherError::NoForecastData,
+            ProviderError::InvalidApiKey => {
+                // Needs the operator's attention, unlike the transient errors below
+                tracing::error!(error = %error_chain(&e), "Weather provider rejected the API key");
+                WeatherError::InvalidApiKey
+            }
+            e => {
+                tracing::warn!(error = %error_chain(&e), "Weather provider request failed");
+                match e {
+                    ProviderError::QuotaExceeded => WeatherError::QuotaExceeded,
+                    ProviderError::Timeout(_) => WeatherError::Timeout,
+                    ProviderError::Decode(_) => WeatherError::InvalidResponse,
+                    _ => WeatherError::ApiFetchError,
+                }
+            }
+        }
+    }
+
+    /// Logs a failed Telegram request
+    fn from_telegram(e: RequestError) -> Self {
+        tracing::error!(error = %error_chain(&e), "Telegram request failed");
+        WeatherError::TelegramError
+    }
+
+    /// Logs a chart that could not be encoded
+    fn from_chart(e: png::EncodingError) -> Self {
+        tracing::error!(error = %error_chain(&e), "Could not encode chart");
+        WeatherError::ChartError
+    }
+
+    /// Returns the keyboard offered with the error message, if any
+    fn keyboard(&self, lang: Languages) -> Option<InlineKeyboardMarkup> {
+        match self {
+            WeatherError::CityNotFound => Some(get_city_not_found_keyboard(lang)),
+            _ => None,
+        }
+    }
+
+    /// Returns user-friendly error message
+    fn user_message(&self, lang: Languages) -> String {
+        get_text(lang, self.locale_key(), None)
     }
 }
 
-/// Generic weather handler used by both `today_handler` and `tomorrow_handler`.
+/// Generic weather handler used by all weather views (today, tomorrow, sun, air quality).
 ///
-/// 1. Reads the `WEATHER_API_KEY` from environment.
-/// 2. Fetcher user's city from the database.
-/// 3. Calls the weather API and extracts forecast using provided selector.
-/// 4. Edits the callback message with forecast result and attach "back to hub" keyboard.
-/// 5. In case of errors (missing API key, no city, API error), responds to the callback query with an error message.
+/// 1. Fetches user's city from the database.
+/// 2. Calls the weather provider and extracts the data for the requested view.
+/// 3. Edits the callback message with the result and attaches "back to hub" keyboard.
+/// 4. In case of errors (no city, provider error), responds to the callback query with an error message.
 async fn weather_handler(
     bot: Bot,
     callback: CallbackQuery,
-    period: WeatherPeriod,
-    db: &DbPool
+    view: WeatherView,
+    db: &Repository,
+    provider: &Provider
 ) -> HandlerResult {
     let callback_id = callback.id.clone();
 
-    let user = UserQueries::get_user(db, callback.user_id())
+    let user = db.get_user(callback.settings_id())
         .await
-        .ok_or_else(|| WeatherError::UserNotFound)
+        .ok_or(WeatherError::UserNotFound)
         .and_then(|u| {
             Languages::from_str(&u.language)
                 .ok_or(WeatherError::UserNotFound)
@@ -114,28 +208,35 @@ async fn weather_handler(
 
     match user {
         Ok((user, lang)) => {
-            match handle_weather_request(
-                &bot,
-                &callback,
-                period,
-                user.city,
-                lang,
-                TemperatureUnits::from_str(&user.temperature_unit).unwrap_or_default()
-            )
+            match handle_weather_request(&bot, &callback, provider, view, user, lang)
                 .await
             {
-                Ok(_) => {
+                Ok(()) => {
                     bot.answer_callback_query(callback_id).await?;
                 }
                 Err(e) => {
+                    metrics::record_weather_error(e.locale_key());
+                    let text = e.user_message(lang);
+
+                    // Keep the message in place with a way out, e.g. to change an unknown city
+                    if let (Some(keyboard), Some(message)) = (e.keyboard(lang), &callback.message)
+                        && let Err(e) = bot.edit_message_text(message.chat().id, message.id(), &text)
+                            .reply_markup(keyboard)
+                            .await
+                    {
+                        // The alert below still tells the user what went wrong
+                        tracing::warn!(error = %error_chain(&e), "Could not show the error message");
+                    }
+
                     bot.answer_callback_query(callback_id)
-                        .text(e.user_message(lang))
+                        .text(text)
                         .show_alert(true)
                         .await?;
                 }
             }
         }
         Err(e) => {
+            metrics::record_weather_error(e.locale_key());
             bot.answer_callback_query(callback_id)
                 .text(e.user_message(Languages::default()))
                 .show_alert(true)
@@ -150,72 +251,280 @@ async fn weather_handler(
 async fn handle_weather_request(
     bot: &Bot,
     callback: &CallbackQuery,
-    period: WeatherPeriod,
-    city: String,
-    lang: Languages,
-    temperature_unit: TemperatureUnits
+    provider: &Provider,
+    view: WeatherView,
+    user: User,
+    lang: Languages
 ) -> Result<(), WeatherError> {
-    let api_key = WEATHER_CONFIG
-        .as_ref()
-        .map_err(|_| WeatherError::MissingApiKey)?;
+    let city = user.city;
+    let temperature_unit = TemperatureUnits::from_str(&user.temperature_unit).unwrap_or_default();
 
     let message = callback.message
         .as_ref()
         .ok_or(WeatherError::MissingMessage)?;
 
-    let weather_response = fetch_forecast(&city, api_key, lang)
-        .await
-        .map_err(|_| WeatherError::ApiFetchError)?;
+    let formatted_message = match view {
+        WeatherView::Forecast(period) => {
+            let weather_response = provider
+                .forecast(&city, lang)
+                .await
+                .map_err(WeatherError::from_provider)?;
+
+            forecast_message(&city, period, &weather_response, lang, temperature_unit)
+                .ok_or(WeatherError::NoForecastData)?
+        }
+        WeatherView::Sun => {
+            let weather_response = provider
+                .forecast(&city, lang)
+                .await
+                .map_err(WeatherError::from_provider)?;
+
+            let sun = sun_times(&weather_response)
+                .ok_or(WeatherError::NoForecastData)?;
+
+            let note = stale_note(weather_response.as_of, Some(weather_response.city.timezone), lang);
+            format!("{}{note}", format_sun_message(&city, &sun, lang))
+        }
+        WeatherView::AirQuality => {
+            let air_quality = provider
+                .air_quality(&city)
+                .await
+                .map_err(WeatherError::from_provider)?;
 
-    let forecast = period
-        .selector()(&weather_response)
-        .ok_or(WeatherError::NoForecastData)?;
+            let note = stale_note(air_quality.as_of, None, lang);
+            format!("{}{note}", format_air_quality_message(&city, &air_quality, lang))
+        }
+        WeatherView::Chart => {
+            let weather_response = provider
+                .forecast(&city, lang)
+                .await
+                .map_err(WeatherError::from_provider)?;
+
+            if weather_response.list.is_empty() {
+                return Err(WeatherError::NoForecastData);
+            }
 
-    let formatted_message = format_weather_message(&city, period, &forecast, lang, temperature_unit);
+            let png = render_chart(&weather_response, temperature_unit)
+                .map_err(WeatherError::from_chart)?;
+            let caption = get_text(lang, "chart-caption", Some(&fluent_args!["city" => html::escape(&city)]))
+                + &stale_note(weather_response.as_of, Some(weather_response.city.timezone), lang);
+
+            // The chart is sent as a new photo so the hub message stays in place
+            bot.send_photo(message.chat().id, InputFile::memory(png).file_name("chart.png"))
+                .caption(caption)
+                .parse_mode(ParseMode::Html)
+                .await
+                .map_err(WeatherError::from_telegram)?;
+
+            return Ok(());
+        }
+    };
 
     bot.edit_message_text(message.chat().id, message.id(), formatted_message)
         .reply_markup(get_to_hub(lang))
         .parse_mode(ParseMode::Html)
         .await
-        .map_err(|_| WeatherError::ApiFetchError)?;
+        .map_err(WeatherError::from_telegram)?;
 
     Ok(())
 }
 
+/// Builds the forecast message for the period.
+///
+/// Returns `None` if there is no forecast for the period.
+pub fn forecast_message(
+    city: &str,
+    period: WeatherPeriod,
+    response: &WeatherResponse,
+    lang: Languages,
+    temperature_unit: TemperatureUnits
+) -> Option<String> {
+    let forecast = period.selector()(response)?;
+
+    // Sun and UV details are shown for the current day only, the day view shows the peak UV
+    let (sun, uv) = match period {
+        WeatherPeriod::Now => (sun_times(response), forecast.uv_index),
+        WeatherPeriod::Today => (sun_times(response), forecast.uv_index_max.or(forecast.uv_index)),
+        WeatherPeriod::Tomorrow => (None, None),
+    };
+
+    let local_time = local_time(forecast, response.city.timezone);
+    let text = format_weather_message(city, period, forecast, local_time, sun.as_ref(), uv, lang, temperature_unit);
+    let note = stale_note(response.as_of, Some(response.city.timezone), lang);
+    Some(format!("{text}{note}"))
+}
+
+/// Returns a one-line summary of the forecast, e.g. "☀️ 21°C, clear sky".
+pub fn forecast_summary(forecast: &Forecast, lang: Languages, temperature_unit: TemperatureUnits) -> String {
+    let weather = forecast.weather.first();
+    let condition = weather
+        .map(|w| w.condition())
+        .unwrap_or(WeatherCondition::Unknown);
+
+    let args = fluent_args![
+        "emoji" => condition.emoji(weather.is_some_and(|w| w.is_night())),
+        "temp" => convert_temperature(forecast.main.temp, temperature_unit),
+        "temp_unit" => temperature_unit.symbol(),
+        "description" => get_text(lang, condition.locale_key(), None),
+    ];
+
+    get_text(lang, "forecast-summary", Some(&args))
+}
+
 /// Formats weather information into a user-friendly message
+///
+/// `local_time` is the time of the forecast in the city, shown as a localized date.
+#[allow(clippy::too_many_arguments)]
 fn format_weather_message(
     city: &str,
     period: WeatherPeriod,
     response: &Forecast,
+    local_time: Option<NaiveDateTime>,
+    sun: Option<&SunTimes>,
+    uv: Option<f64>,
     lang: Languages,
     temperature_unit: TemperatureUnits
 ) -> String {
-    let description = &response.weather[0].description;
-    let emoji = weather_to_emoji(description);
+    let weather = response.weather.first();
+    let condition = weather
+        .map(|w| w.condition())
+        .unwrap_or(WeatherCondition::Unknown);
+    let emoji = condition.emoji(weather.is_some_and(|w| w.is_night()));
+    let description = get_text(lang, condition.locale_key(), None);
     let temp = convert_temperature(response.main.temp, temperature_unit);
     let feels_like = convert_temperature(response.main.feels_like, temperature_unit);
-    let wind_speed = if response.wind.speed as i32 == 0 {
+    let wind_speed = if response.wind.speed.round() == 0.0 {
         get_text(lang, "weather-wind-speed-unknown", None)
     }
     else {
-        format!("{} {}", response.wind.speed as i32, get_text(lang, "weather-wind-speed-kmh", None))
+        format!("{} {}", response.wind.speed.round() as i64, get_text(lang, "weather-wind-speed-kmh", None))
     };
+    let date_line = local_time
+        .map(|time| {
+            let args = fluent_args!["date" => FluentDateTime::from(time)];
+            format!("\n{}", get_text(lang, "weather-date-line", Some(&args)))
+        })
+        .unwrap_or_default();
+    let sun_line = sun
+        .map(|sun| {
+            let args = fluent_args![
+                "sunrise" => sun.sunrise.format("%H:%M").to_string(),
+                "sunset" => sun.sunset.format("%H:%M").to_string(),
+            ];
+            format!("\n{}", get_text(lang, "weather-sun-line", Some(&args)))
+        })
+        .unwrap_or_default();
+    let uv_line = uv
+        .map(|uv| {
+            let risk = UvRisk::from_index(uv);
+            let args = fluent_args![
+                "emoji" => risk.emoji(),
+                "uv" => uv.round() as i64,
+                "risk" => get_text(lang, risk.locale_key(), None),
+                "advice" => get_text(lang, risk.advice_key(), None),
+            ];
+            format!("\n{}", get_text(lang, "weather-uv-line", Some(&args)))
+        })
+        .unwrap_or_default();
 
     let args = fluent_args![
-        "city" => city,
+        "city" => html::escape(city),
         "day" => period.label(lang).to_lowercase(),
         "emoji" => emoji,
-        "description" => capitalize_first_letter(description),
-        "temp" => temp as i32,
-        "feels_like" => feels_like as i32,
+        "description" => capitalize_first_letter(&description),
+        "date" => date_line,
+        "temp" => temp,
+        "feels_like" => feels_like,
         "humidity" => response.main.humidity,
         "wind_speed" => wind_speed,
         "temp_unit" => temperature_unit.as_str(),
+        "uv" => uv_line,
+        "sun" => sun_line,
     ];
 
     get_text(lang, "weather", Some(&args))
 }
 
+/// Returns the time of the forecast slot in the city's local time.
+///
+/// # Arguments
+/// - `forecast` - slot with the time in UTC
+/// - `timezone` - shift of the city from UTC in seconds
+fn local_time(forecast: &Forecast, timezone: i32) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(&forecast.dt_txt, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|dt| dt + Duration::seconds(timezone as i64))
+}
+
+/// Renders the chart for the next 48 hours in the city's local time
+fn render_chart(response: &WeatherResponse, temperature_unit: TemperatureUnits) -> Result<Vec<u8>, png::EncodingError> {
+    let points = response.list
+        .iter()
+        .take(CHART_SLOTS)
+        .map(|f| ChartPoint {
+            hour: local_time(f, response.city.timezone)
+                .map(|dt| dt.hour())
+                .unwrap_or_default(),
+            temp: convert_temperature(f.main.temp, temperature_unit),
+            pop: f.pop,
+        })
+        .collect::<Vec<_>>();
+
+    render_forecast_chart(&points, temperature_unit.symbol())
+}
+
+/// Returns the note appended to data served from the cache while the provider is unavailable.
+///
+/// # Arguments
+/// - `as_of` - fetch time of stale data, `None` for fresh data
+/// - `timezone` - shift from UTC in seconds to show the city's local time, `None` for UTC
+///
+/// # Returns
+/// An empty string for fresh data.
+fn stale_note(as_of: Option<DateTime<Utc>>, timezone: Option<i32>, lang: Languages) -> String {
+    let Some(as_of) = as_of else {
+        return String::new();
+    };
+
+    let time = match timezone {
+        Some(offset) => (as_of + Duration::seconds(offset as i64)).format("%H:%M").to_string(),
+        None => as_of.format("%H:%M UTC").to_string(),
+    };
+
+    format!("\n\n{}", get_text(lang, "stale-data", Some(&fluent_args!["time" => time])))
+}
+
+/// Formats sunrise, sunset and day length into a user-friendly message
+fn format_sun_message(city: &str, sun: &SunTimes, lang: Languages) -> String {
+    let args = fluent_args![
+        "city" => html::escape(city),
+        "sunrise" => sun.sunrise.format("%H:%M").to_string(),
+        "sunset" => sun.sunset.format("%H:%M").to_string(),
+        "hours" => sun.day_length.num_hours(),
+        "minutes" => sun.day_length.num_minutes() % 60,
+    ];
+
+    get_text(lang, "sun", Some(&args))
+}
+
+/// Formats air quality index, pollutants and health advice into a user-friendly message
+fn format_air_quality_message(city: &str, air_quality: &AirQuality, lang: Languages) -> String {
+    let index = air_quality.index;
+    let args = fluent_args![
+        "city" => html::escape(city),
+        "emoji" => index.emoji(),
+        "aqi" => index.as_index(),
+        "category" => get_text(lang, index.locale_key(), None),
+        "advice" => get_text(lang, index.advice_key(), None),
+        "pm2_5" => air_quality.pm2_5,
+        "pm10" => air_quality.pm10,
+        "o3" => air_quality.o3,
+        "no2" => air_quality.no2,
+    ];
+
+    get_text(lang, "air-quality", Some(&args))
+}
+
 fn convert_temperature(celsius: f64, unit: TemperatureUnits) -> f64 {
     match unit {
         TemperatureUnits::Celsius => celsius,
@@ -224,32 +533,75 @@ fn convert_temperature(celsius: f64, unit: TemperatureUnits) -> f64 {
     }
 }
 
-const WEATHER_EMOJI_PATTERNS: &[(&[&str], &str)] = &[
-    (&["дощ", "rain", "regen"], "🌧️"),
-    (&["сніг", "snow", "schnee"], "❄️"),
-    (&["хмар", "похмуро", "cloud", "cloudy", "wolken", "bewölkt"], "☁️"),
-    (&["ясно", "сонячно", "sun", "sunny", "klar"], "☀️"),
-    (&["туман", "fog", "mist", "nebel"], "🌫️"),
-    (&["гроза", "thunder", "storm", "gewitter"], "⛈️"),
-];
+/// Handles the `/weather` command: sends today's forecast for the chat's city.
+///
+/// In group chats the group's shared city, language and units are used.
+pub async fn command_handler(
+    bot: Bot,
+    msg: Message,
+    db: Repository,
+    provider: Provider,
+    config: Arc<Config>,
+) -> HandlerResult {
+    let Some(user) = db.get_user(msg.settings_id()).await else {
+        let lang = Languages::detect(msg.language_code(), &config.language_fallbacks);
+        metrics::record_weather_error(WeatherError::UserNotFound.locale_key());
+        bot.send_message(msg.chat.id, WeatherError::UserNotFound.user_message(lang)).await?;
+        return Ok(());
+    };
 
-/// Returns weather emoji
-fn weather_to_emoji(description: &str) -> &'static str {
-    WEATHER_EMOJI_PATTERNS
-        .iter()
-        .find(|(patterns, _)| {
-            patterns.iter().any(|pattern| description.to_lowercase().contains(pattern))
-        })
-        .map(|(_, emoji)| *emoji)
-        .unwrap_or("🌤️")
+    let lang = Languages::from_str(&user.language).unwrap_or_default();
+    let temperature_unit = TemperatureUnits::from_str(&user.temperature_unit).unwrap_or_default();
+
+    let result = provider
+        .forecast(&user.city, lang)
+        .await
+        .map_err(WeatherError::from_provider)
+        .and_then(|response| {
+            forecast_message(&user.city, WeatherPeriod::Today, &response, lang, temperature_unit)
+                .ok_or(WeatherError::NoForecastData)
+        });
+
+    match result {
+        Ok(text) => {
+            bot.send_message(msg.chat.id, text)
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        Err(e) => {
+            metrics::record_weather_error(e.locale_key());
+            let mut request = bot.send_message(msg.chat.id, e.user_message(lang));
+            if let Some(keyboard) = e.keyboard(lang) {
+                request = request.reply_markup(keyboard);
+            }
+            request.await?;
+        }
+    }
+
+    Ok(())
 }
 
 /// Handler for today weather.
-pub async fn today_handler(bot: Bot, callback: CallbackQuery, db: DbPool) -> HandlerResult {
-    weather_handler(bot, callback, WeatherPeriod::Today, &db).await
+pub async fn today_handler(bot: Bot, callback: CallbackQuery, db: Repository, provider: Provider) -> HandlerResult {
+    weather_handler(bot, callback, WeatherView::Forecast(WeatherPeriod::Today), &db, &provider).await
 }
 
 /// Handler for tomorrow weather.
-pub async fn tomorrow_handler(bot: Bot, callback: CallbackQuery, db: DbPool) -> HandlerResult {
-    weather_handler(bot, callback, WeatherPeriod::Tomorrow, &db).await
+pub async fn tomorrow_handler(bot: Bot, callback: CallbackQuery, db: Repository, provider: Provider) -> HandlerResult {
+    weather_handler(bot, callback, WeatherView::Forecast(WeatherPeriod::Tomorrow), &db, &provider).await
+}
+
+/// Handler for sunrise and sunset.
+pub async fn sun_handler(bot: Bot, callback: CallbackQuery, db: Repository, provider: Provider) -> HandlerResult {
+    weather_handler(bot, callback, WeatherView::Sun, &db, &provider).await
+}
+
+/// Handler for air quality.
+pub async fn air_quality_handler(bot: Bot, callback: CallbackQuery, db: Repository, provider: Provider) -> HandlerResult {
+    weather_handler(bot, callback, WeatherView::AirQuality, &db, &provider).await
+}
+
+/// Handler for the forecast chart.
+pub async fn chart_handler(bot: Bot, callback: CallbackQuery, db: Repository, provider: Provider) -> HandlerResult {
+    weather_handler(bot, callback, WeatherView::Chart, &db, &provider).await
 }
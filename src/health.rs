@@ -0,0 +1,99 @@
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use tokio::time::timeout;
+
+use crate::api::Provider;
+use crate::db::Repository;
+
+/// How long a provider check result is reused, so probes don't spend API quota.
+const PROVIDER_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Maximum time a single dependency check may take.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Dependencies inspected by the readiness probe.
+pub struct HealthState {
+    users: Repository,
+    provider: Provider,
+    dispatcher_running: Arc<AtomicBool>,
+    /// Last provider check: when it ran and whether it succeeded.
+    provider_status: Mutex<Option<(Instant, bool)>>,
+}
+
+impl HealthState {
+    /// # Arguments
+    /// - `users` - user storage to check connectivity of
+    /// - `provider` - weather provider to ping
+    /// - `dispatcher_running` - flag set while the dispatcher is receiving updates,
+    ///   cleared when shutdown starts or the dispatcher stops
+    pub fn new(users: Repository, provider: Provider, dispatcher_running: Arc<AtomicBool>) -> Self {
+        Self {
+            users,
+            provider,
+            dispatcher_running,
+            provider_status: Mutex::new(None),
+        }
+    }
+
+    /// Pings the user storage.
+    async fn database_ready(&self) -> bool {
+        matches!(timeout(CHECK_TIMEOUT, self.users.ping()).await, Ok(Ok(())))
+    }
+
+    /// Pings the provider, reusing the last result for [`PROVIDER_CHECK_INTERVAL`].
+    async fn provider_ready(&self) -> bool {
+        if let Some((checked, ok)) = *self.provider_status.lock().unwrap()
+            && checked.elapsed() < PROVIDER_CHECK_INTERVAL
+        {
+            return ok;
+        }
+
+        let ok = matches!(timeout(CHECK_TIMEOUT, self.provider.ping()).await, Ok(Ok(())));
+        *self.provider_status.lock().unwrap() = Some((Instant::now(), ok));
+        ok
+    }
+}
+
+/// Serves the health probes until the process exits.
+///
+/// - `GET /healthz` - always `200 OK` while the process is alive
+/// - `GET /readyz` - `200 OK` if the database, the weather provider and the
+///   dispatcher are all available, otherwise `503 Service Unavailable`.
+///   The body lists the status of each check.
+pub async fn serve(address: SocketAddr, state: Arc<HealthState>) -> io::Result<()> {
+    let router = Router::new()
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(address).await?;
+    axum::serve(listener, router).await
+}
+
+async fn healthz() -> &'static str {
+    "ok"
+}
+
+async fn readyz(State(state): State<Arc<HealthState>>) -> (StatusCode, String) {
+    let (database, provider) = tokio::join!(state.database_ready(), state.provider_ready());
+    let dispatcher = state.dispatcher_running.load(Ordering::Relaxed);
+
+    let status = if database && provider && dispatcher {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    let line = |name: &str, ok: bool| format!("{name}: {}\n", if ok { "ok" } else { "fail" });
+    let body = line("database", database) + &line("provider", provider) + &line("dispatcher", dispatcher);
+
+    (status, body)
+}
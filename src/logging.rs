@@ -0,0 +1,116 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::ops::ControlFlow;
+use std::panic::Location;
+
+use teloxide::dispatching::UpdateHandler;
+use teloxide::dptree::di::DependencyMap;
+use teloxide::dptree::{self, Cont, HandlerSignature, Type};
+use teloxide::types::Update;
+use tracing::{field, info_span, Instrument, Span};
+use tracing_subscriber::EnvFilter;
+
+use crate::types::HandlerResult;
+
+/// Log filter used when `RUST_LOG` is not set.
+const DEFAULT_FILTER: &str = "info";
+
+/// Output format of the logs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Human-readable lines.
+    #[default]
+    Text,
+    /// One JSON object per line, for log pipelines.
+    Json,
+}
+
+impl LogFormat {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(LogFormat::Text),
+            "json" => Some(LogFormat::Json),
+            &_ => None,
+        }
+    }
+}
+
+/// Installs the global log subscriber.
+///
+/// Levels are filtered by `RUST_LOG` (default `info`). Records from crates
+/// using the `log` facade (e.g. teloxide) are forwarded as well.
+pub fn init(format: LogFormat) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter);
+
+    match format {
+        LogFormat::Text => subscriber.init(),
+        LogFormat::Json => subscriber.json().flatten_event(true).with_span_list(false).init(),
+    }
+}
+
+/// Formats an error with all of its sources, e.g. `request failed: error sending request: connection refused`.
+pub fn error_chain(error: &dyn Error) -> String {
+    let mut text = error.to_string();
+    let mut source = error.source();
+
+    while let Some(e) = source {
+        text.push_str(": ");
+        text.push_str(&e.to_string());
+        source = e.source();
+    }
+
+    text
+}
+
+/// Schema step that runs the rest of the update handling inside an `update` span.
+///
+/// The span carries the update ID, user ID, chat ID and callback data.
+/// The `handler` field is filled in by [`record_handler`] once a handler is chosen.
+/// Handler errors are logged inside the span.
+#[track_caller]
+pub fn update_span() -> UpdateHandler<Box<dyn Error + Send + Sync + 'static>> {
+    dptree::from_fn(
+        |deps: DependencyMap, cont: Cont<'static, HandlerResult>| async move {
+            let update = deps.get::<Update>();
+            let span = info_span!(
+                "update",
+                update_id = update.id.0,
+                user_id = field::Empty,
+                chat_id = field::Empty,
+                callback = field::Empty,
+                handler = field::Empty,
+            );
+
+            if let Some(user) = update.from() {
+                span.record("user_id", user.id.0);
+            }
+            if let Some(chat) = update.chat() {
+                span.record("chat_id", chat.id.0);
+            }
+            if let teloxide::types::UpdateKind::CallbackQuery(query) = &update.kind {
+                span.record("callback", query.data.as_deref());
+            }
+
+            let result = cont(deps).instrument(span.clone()).await;
+
+            if let ControlFlow::Break(Err(e)) = &result {
+                span.in_scope(|| tracing::error!(error = %error_chain(e.as_ref()), "Handler failed"));
+            }
+
+            result
+        },
+        HandlerSignature::Other {
+            obligations: BTreeMap::from([(Type::of::<Update>(), Location::caller())]),
+            guaranteed_outcomes: BTreeSet::new(),
+            conditional_outcomes: BTreeSet::new(),
+            continues: true,
+        },
+    )
+}
+
+/// Records the chosen handler in the current `update` span.
+pub fn record_handler(handler: &'static str) {
+    Span::current().record("handler", handler);
+    tracing::debug!("Handling update");
+}
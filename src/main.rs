@@ -1,3 +1,4 @@
+mod activity;
 mod handlers;
 mod db;
 mod states;
@@ -7,54 +8,175 @@ mod utils;
 mod enums;
 mod api;
 mod traits;
+mod webhook;
+mod throttle;
+mod config;
+mod delivery;
+mod health;
+mod logging;
+mod metrics;
+mod uv_alerts;
 
-use std::env;
-use dotenvy::dotenv;
+#[cfg(test)]
+mod tests;
+
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use teloxide::dispatching::dialogue::InMemStorage;
+use teloxide::error_handlers::IgnoringErrorHandler;
 use teloxide::prelude::*;
 
-use crate::db::pool::init_db;
+use crate::activity::Activity;
+use crate::api::{CachedProvider, FixtureProvider, HttpClient, MeteredProvider, OpenMeteo, OpenWeatherMap, Provider};
+use crate::config::{Config, ProviderKind};
+use crate::db::pool;
+use crate::db::{MemoryUserRepository, Repository};
+use crate::delivery::Delivery;
+use crate::enums::languages::Languages;
+use crate::health::HealthState;
 use crate::schema::schema;
 use crate::states::State;
+use crate::throttle::{InlineThrottle, Throttle};
 
 /// Entry point of the Telegram bot application.
 ///
 /// Steps performed in `main`:
-/// 1. Loads environment variables from `.env` file.
-/// 2. Reads the Telegram bot token from `TELEGRAM_TOKEN`.
-/// 3. Initializes the bot instance with `Bot::new`.
-/// 4. Initializes the PostgreSQL database `DATABASE_URL`.
+/// 1. Loads and validates the [`Config`] (environment, `.env` and optional `config.toml`),
+///    then sets up logging and loads the languages found in `locales/`.
+/// 2. Initializes the bot instance with `Bot::new`.
+/// 3. Initializes the PostgreSQL or SQLite user repository selected by the `DATABASE_URL` scheme,
+///    or an in-memory one if it is unset.
+/// 4. Selects the weather provider on a shared HTTP client with timeouts and retries, and wraps it
+///    with request metrics and an in-memory cache that serves stale data while the provider is down.
 /// 5. Sets up in-memory dialogue storage for user states.
 /// 6. Builds the `Dispatcher` with the bot, update schema, and dependencies.
-/// 7. Starts polling updates and handlers Ctrl+C gracefully.
+/// 7. Starts the health probe server if `HEALTH_ADDR` is set, and the metrics
+///    endpoint if `METRICS_ADDR` is set.
+/// 8. Receives updates by long polling, or over a webhook if `BOT_MODE=webhook`,
+///    and handles Ctrl+C gracefully.
 #[tokio::main]
 async fn main() {
-    dotenv().ok(); // Load .env variables
+    // Load configuration, exit with a readable message if it is invalid
+    let config = match Config::load() {
+        Ok(config) => Arc::new(config),
+        Err(e) => {
+            eprintln!("Configuration error: {e}");
+            process::exit(1);
+        }
+    };
+
+    logging::init(config.log_format);
+
+    // Read the bundles in `locales/` now, so a broken translation stops the bot at startup
+    let languages = Languages::all().map(|l| l.as_str()).collect::<Vec<_>>();
+    tracing::info!(?languages, "Loaded languages");
 
-    // Read Telegram token
-    let token = env::var("TELEGRAM_TOKEN")
-        .expect("TELEGRAM_TOKEN not found in .env file");
+    // Initializes the bot instance, optionally against a custom Bot API server
+    let bot = match &config.telegram_api_url {
+        Some(url) => Bot::new(&config.telegram_token).set_api_url(url.clone()),
+        None => Bot::new(&config.telegram_token),
+    };
 
-    // Initializes the bot instance
-    let bot = Bot::new(token);
+    // Initialize the user repository
+    let users: Repository = match &config.database_url {
+        Some(url) => pool::connect(url, config.db_pool_size)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::error!(error = %logging::error_chain(e.as_ref()), "Could not initialize database pool");
+                process::exit(1);
+            }),
+        None => {
+            tracing::warn!("DATABASE_URL is not set, user settings are kept in memory and lost on restart");
+            Arc::new(MemoryUserRepository::default())
+        }
+    };
 
-    // Read database url
-    let database_url = env::var("DATABASE_URL")
-        .expect("DATABASE_URL not found in .env file");
+    // Select the weather provider
+    let http = HttpClient::new(config.provider_connect_timeout, config.provider_timeout, config.provider_retries)
+        .unwrap_or_else(|e| {
+            tracing::error!(error = %logging::error_chain(&e), "Could not create HTTP client");
+            process::exit(1);
+        });
+    let source: Provider = match config.provider {
+        ProviderKind::Fixture => Arc::new(FixtureProvider),
+        ProviderKind::OpenMeteo => Arc::new(OpenMeteo::new(http)),
+        ProviderKind::OpenWeatherMap => {
+            let api_key = config.weather_api_key.clone().unwrap_or_default();
+            Arc::new(OpenWeatherMap::new(http, api_key))
+        }
+    };
+    let provider: Provider = Arc::new(CachedProvider::new(
+        Arc::new(MeteredProvider::new(source)),
+        config.forecast_cache_ttl,
+        config.air_quality_cache_ttl,
+    ));
 
-    // Initialize the database connection pool
-    let pool = init_db(&database_url)
-        .await
-        .expect("Could not initialize database pool");
+    // Rate-limited queue for outbound messages
+    let delivery = Delivery::new(bot.clone(), users.clone(), config.delivery_rate);
+
+    // Per-user limit for weather requests
+    let throttle = Arc::new(Throttle::new(config.throttle_limit, config.throttle_window));
+    let inline_throttle = Arc::new(InlineThrottle(Throttle::new(config.inline_throttle_limit, config.throttle_window)));
+
+    // Limits activity writes to the database per user
+    let activity = Arc::new(Activity::default());
 
     // Create in-memory storage for user dialogue states
     let storage = InMemStorage::<State>::new();
 
-    // Build and run the dispatcher
-    Dispatcher::builder(bot, schema())
+    // Build the dispatcher
+    let mut dispatcher = Dispatcher::builder(bot.clone(), schema())
         .enable_ctrlc_handler()
-        .dependencies(dptree::deps![pool.clone(), storage.clone()])
-        .build()
-        .dispatch()
-        .await;
+        // Handler errors are already logged inside the update span
+        .error_handler(IgnoringErrorHandler::new())
+        .dependencies(dptree::deps![users.clone(), storage.clone(), provider.clone(), config.clone(), delivery.clone(), throttle, inline_throttle, activity])
+        .build();
+
+    // Start the health probes
+    let dispatcher_running = Arc::new(AtomicBool::new(false));
+    if let Some(address) = config.health_addr {
+        let state = Arc::new(HealthState::new(users.clone(), provider.clone(), dispatcher_running.clone()));
+        tokio::spawn(async move {
+            if let Err(e) = health::serve(address, state).await {
+                tracing::error!(error = %e, "Health server failed");
+            }
+        });
+    }
+
+    // Start the metrics endpoint
+    if let Some(address) = config.metrics_addr {
+        tokio::spawn(async move {
+            if let Err(e) = metrics::serve(address).await {
+                tracing::error!(error = %e, "Metrics server failed");
+            }
+        });
+    }
+
+    // Start the daily UV alerts
+    tokio::spawn(uv_alerts::run(users.clone(), provider.clone(), delivery));
+
+    // Readiness fails as soon as Ctrl+C starts the shutdown, while pending updates are still handled
+    let ctrlc_flag = dispatcher_running.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            ctrlc_flag.store(false, Ordering::Relaxed);
+        }
+    });
+
+    // Run with webhook or long polling
+    dispatcher_running.store(true, Ordering::Relaxed);
+    let result = match &config.webhook {
+        Some(webhook) => webhook::run(dispatcher, bot, webhook).await,
+        None => {
+            dispatcher.dispatch().await;
+            Ok(())
+        }
+    };
+    dispatcher_running.store(false, Ordering::Relaxed);
+
+    if let Err(e) = result {
+        tracing::error!(error = %logging::error_chain(e.as_ref()), "Webhook server failed");
+        process::exit(1);
+    }
 }
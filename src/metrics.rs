@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge, HistogramTimer,
+    HistogramVec, IntCounterVec, IntGauge, TextEncoder,
+};
+use teloxide::types::User;
+
+/// Users with an update within this window count as active.
+const ACTIVE_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+static UPDATES: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!("weather_bot_updates_total", "Updates handled, by handler", &["handler"])
+        .unwrap()
+});
+
+static CALLBACKS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!("weather_bot_callbacks_total", "Callback queries received, by data", &["callback"])
+        .unwrap()
+});
+
+static WEATHER_ERRORS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!("weather_bot_weather_errors_total", "Weather errors shown to users, by kind", &["error"])
+        .unwrap()
+});
+
+static PROVIDER_REQUESTS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "weather_bot_provider_requests_total",
+        "Weather provider requests, by operation and status",
+        &["operation", "status"]
+    )
+        .unwrap()
+});
+
+static PROVIDER_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "weather_bot_provider_request_duration_seconds",
+        "Weather provider request latency, by operation",
+        &["operation"]
+    )
+        .unwrap()
+});
+
+static DB_QUERY_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "weather_bot_db_query_duration_seconds",
+        "Database query latency, by query",
+        &["query"],
+        vec![0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0]
+    )
+        .unwrap()
+});
+
+static CACHE_REQUESTS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "weather_bot_cache_requests_total",
+        "Provider cache lookups, by cache and result (hit, miss or stale)",
+        &["cache", "result"]
+    )
+        .unwrap()
+});
+
+static ACTIVE_USERS: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!("weather_bot_active_users", "Users with an update in the last 24 hours").unwrap()
+});
+
+/// Last update time of every recently seen user.
+static LAST_SEEN: LazyLock<Mutex<HashMap<u64, Instant>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Counts an update reaching a handler.
+///
+/// # Arguments
+/// - `handler` - handler label, e.g. `weather::today`
+pub fn record_update(handler: &str) {
+    UPDATES.with_label_values(&[handler]).inc();
+}
+
+/// Counts a callback query by its data.
+///
+/// Unknown data is counted as `unknown` to keep the label set bounded.
+pub fn record_callback(data: Option<&str>) {
+    let label = data
+        .and_then(crate::enums::Callbacks::from_str)
+        .map_or("unknown", |c| c.as_str());
+    CALLBACKS.with_label_values(&[label]).inc();
+}
+
+/// Counts a weather error shown to the user.
+pub fn record_weather_error(kind: &str) {
+    WEATHER_ERRORS.with_label_values(&[kind]).inc();
+}
+
+/// Records a finished provider request.
+///
+/// # Arguments
+/// - `operation` - provider method, e.g. `forecast`
+/// - `status` - HTTP status code, `ok` or an error kind
+/// - `elapsed` - request duration
+pub fn record_provider_request(operation: &str, status: &str, elapsed: Duration) {
+    PROVIDER_REQUESTS.with_label_values(&[operation, status]).inc();
+    PROVIDER_DURATION.with_label_values(&[operation]).observe(elapsed.as_secs_f64());
+}
+
+/// Starts timing a database query. The duration is recorded when the timer is dropped.
+pub fn db_timer(query: &str) -> HistogramTimer {
+    DB_QUERY_DURATION.with_label_values(&[query]).start_timer()
+}
+
+/// Counts a provider cache lookup.
+pub fn record_cache(cache: &str, hit: bool) {
+    let result = if hit { "hit" } else { "miss" };
+    CACHE_REQUESTS.with_label_values(&[cache, result]).inc();
+}
+
+/// Counts stale cached data served while the provider is unavailable.
+pub fn record_stale(cache: &str) {
+    CACHE_REQUESTS.with_label_values(&[cache, "stale"]).inc();
+}
+
+/// Marks the sender of an update as active.
+pub fn record_active_user(user: Option<&User>) {
+    if let Some(user) = user {
+        LAST_SEEN.lock().unwrap().insert(user.id.0, Instant::now());
+    }
+}
+
+/// Removes the user from the active users, e.g. after their data was deleted.
+pub fn forget_active_user(user_id: u64) {
+    LAST_SEEN.lock().unwrap().remove(&user_id);
+}
+
+/// Serves `GET /metrics` in the Prometheus text format until the process exits.
+pub async fn serve(address: SocketAddr) -> io::Result<()> {
+    let router = Router::new().route("/metrics", get(metrics));
+
+    let listener = tokio::net::TcpListener::bind(address).await?;
+    axum::serve(listener, router).await
+}
+
+async fn metrics() -> impl IntoResponse {
+    {
+        let mut last_seen = LAST_SEEN.lock().unwrap();
+        last_seen.retain(|_, seen| seen.elapsed() < ACTIVE_WINDOW);
+        ACTIVE_USERS.set(last_seen.len() as i64);
+    }
+
+    match TextEncoder::new().encode_to_string(&prometheus::gather()) {
+        Ok(body) => (StatusCode::OK, [(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)], body).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
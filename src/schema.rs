@@ -1,109 +1,278 @@
+use std::sync::Arc;
+
 use teloxide::dispatching::{dialogue, UpdateHandler};
 use teloxide::dispatching::dialogue::InMemStorage;
 use teloxide::dptree;
 use teloxide::prelude::*;
 
-use crate::enums::{Callbacks, Commands};
-use crate::handlers::{receive_city, start, weather, settings};
+use crate::activity::Activity;
+use crate::db::Repository;
+use crate::enums::{AdminCommands, Callbacks, Commands};
+use crate::handlers::{admin, inline, privacy, receive_city, start, throttled, weather, settings};
+use crate::logging;
+use crate::metrics;
 use crate::states::State;
+use crate::throttle::{InlineThrottle, Throttle};
 
 /// Bot's update handling schema.
 ///
 /// Branches:
+/// - Admin commands
 /// - Commands
 /// - Dialogue
 /// - Callback queries
+/// - Inline queries
 ///
 /// Returns an ['UpdateHandler'] tree ready for the dispatcher.
 pub fn schema() -> UpdateHandler<Box<dyn std::error::Error + Send + Sync + 'static>> {
     use dptree::case;
 
     let commands = teloxide::filter_command::<Commands, _>()
-        .branch(case![Commands::Start].endpoint(start::message_handler));
+        .branch(
+            case![Commands::Start]
+                .inspect(handler("start::message"))
+                .endpoint(start::message_handler)
+        )
+        .branch(
+            case![Commands::Weather]
+                .branch(
+                    dptree::filter_map(|msg: Message, throttle: Arc<Throttle>| {
+                        msg.from.as_ref().and_then(|user| throttle.check(user.id.0))
+                    })
+                        .inspect(handler("throttled::command"))
+                        .endpoint(throttled::message_handler)
+                )
+                .branch(
+                    dptree::entry()
+                        .inspect(handler("weather::command"))
+                        .endpoint(weather::command_handler)
+                )
+        )
+        .branch(
+            case![Commands::DeleteMe]
+                .inspect(handler("privacy::delete"))
+                .endpoint(privacy::delete_command_handler)
+        )
+        .branch(
+            case![Commands::Export]
+                .inspect(handler("privacy::export"))
+                .endpoint(privacy::export_handler)
+        );
+
+    // Admin commands from other users fall through to the regular branches
+    let admin_commands = teloxide::filter_command::<AdminCommands, _>()
+        .filter(admin::is_admin)
+        .branch(
+            case![AdminCommands::Stats]
+                .inspect(handler("admin::stats"))
+                .endpoint(admin::stats_handler)
+        )
+        .branch(
+            case![AdminCommands::Broadcast(text)]
+                .inspect(handler("admin::broadcast"))
+                .endpoint(admin::broadcast_handler)
+        )
+        .branch(
+            case![AdminCommands::User(id)]
+                .inspect(handler("admin::user"))
+                .endpoint(admin::user_handler)
+        );
 
     let dialogue = dialogue::enter::<Update, InMemStorage<State>, State, _>()
         .branch(
             Update::filter_message()
+                .branch(admin_commands)
                 .branch(commands)
-                .branch(case![State::ReceiveCity].endpoint(receive_city::handler))
+                .branch(
+                    case![State::ReceiveCity]
+                        .inspect(handler("receive_city"))
+                        .endpoint(receive_city::handler)
+                )
         );
 
     let callback_queries = Update::filter_callback_query()
+        .inspect(|q: CallbackQuery| metrics::record_callback(q.data.as_deref()))
         .enter_dialogue::<CallbackQuery, InMemStorage<State>, State>()
+        // Requests to the weather provider over the per-user limit are answered with an alert
+        .branch(
+            dptree::filter_map(|q: CallbackQuery, throttle: Arc<Throttle>| {
+                q.data.as_deref()
+                    .and_then(Callbacks::from_str)
+                    .filter(Callbacks::fetches_weather)
+                    .and_then(|_| throttle.check(q.from.id.0))
+            })
+                .inspect(handler("throttled::callback"))
+                .endpoint(throttled::callback_handler),
+        )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Start.as_str()))
+                .inspect(handler("start::callback"))
                 .endpoint(start::callback_handler),
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Today.as_str()))
+                .inspect(handler("weather::today"))
                 .endpoint(weather::today_handler),
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Tomorrow.as_str()))
+                .inspect(handler("weather::tomorrow"))
                 .endpoint(weather::tomorrow_handler),
         )
+        .branch(
+            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Sun.as_str()))
+                .inspect(handler("weather::sun"))
+                .endpoint(weather::sun_handler),
+        )
+        .branch(
+            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::AirQuality.as_str()))
+                .inspect(handler("weather::air_quality"))
+                .endpoint(weather::air_quality_handler),
+        )
+        .branch(
+            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Chart.as_str()))
+                .inspect(handler("weather::chart"))
+                .endpoint(weather::chart_handler),
+        )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::SettingsHub.as_str()))
+                .inspect(handler("settings::hub"))
                 .endpoint(settings::hub::handler),
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::SelectLanguage.as_str()))
+                .inspect(handler("settings::language::hub"))
                 .endpoint(settings::language::hub::handler),
         )
         .branch(
-            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Ukrainian.as_str()))
-                .endpoint(settings::language::ukrainian_handler)
+            dptree::filter_map(|q: CallbackQuery| match q.data.as_deref().and_then(Callbacks::from_str) {
+                Some(Callbacks::Language(lang)) => Some(lang),
+                _ => None,
+            })
+                .inspect(handler("settings::language::select"))
+                .endpoint(settings::language::select::handler)
         )
         .branch(
-            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::English.as_str()))
-                .endpoint(settings::language::english_handler)
+            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::SelectUnits.as_str()))
+                .inspect(handler("settings::units::hub"))
+                .endpoint(settings::units::hub::handler)
         )
         .branch(
-            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Deutsch.as_str()))
-                .endpoint(settings::language::deutsch_handler)
+            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::ChangeCity.as_str()))
+                .inspect(handler("settings::city"))
+                .endpoint(settings::city::handler)
         )
         .branch(
-            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::SelectUnits.as_str()))
-                .endpoint(settings::units::hub::handler)
+            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::UvAlerts.as_str()))
+                .inspect(handler("settings::uv_alerts"))
+                .endpoint(settings::uv_alerts::handler)
+        )
+        .branch(
+            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::DeleteConfirm.as_str()))
+                .inspect(handler("privacy::delete_confirm"))
+                .endpoint(privacy::delete_confirm_handler)
+        )
+        .branch(
+            dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::DeleteCancel.as_str()))
+                .inspect(handler("privacy::delete_cancel"))
+                .endpoint(privacy::delete_cancel_handler)
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Temperature.as_str()))
+                .inspect(handler("settings::units::temperature::hub"))
                 .endpoint(settings::units::temperature::hub::handler)
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Celsius.as_str()))
+                .inspect(handler("settings::units::temperature::select::celsius"))
                 .endpoint(settings::units::temperature::select::celsius_handler)
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Fahrenheit.as_str()))
+                .inspect(handler("settings::units::temperature::select::fahrenheit"))
                 .endpoint(settings::units::temperature::select::fahrenheit_handler)
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Kelvin.as_str()))
+                .inspect(handler("settings::units::temperature::select::kelvin"))
                 .endpoint(settings::units::temperature::select::kelvin_handler)
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Speed.as_str()))
+                .inspect(handler("settings::units::speed::hub"))
                 .endpoint(settings::units::speed::hub::handler)
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::KilometersPerHour.as_str()))
+                .inspect(handler("settings::units::speed::select::khp"))
                 .endpoint(settings::units::speed::select::khp_handler)
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::MetersPerMinute.as_str()))
+                .inspect(handler("settings::units::speed::select::mps"))
                 .endpoint(settings::units::speed::select::mps_handler)
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::MilesPerHour.as_str()))
+                .inspect(handler("settings::units::speed::select::mph"))
                 .endpoint(settings::units::speed::select::mph_handler)
         )
         .branch(
             dptree::filter(|q: CallbackQuery| q.data.as_deref() == Some(Callbacks::Knots.as_str()))
+                .inspect(handler("settings::units::speed::select::knots"))
                 .endpoint(settings::units::speed::select::knots_handler)
         );
 
+    let inline_queries = Update::filter_inline_query()
+        .branch(
+            dptree::filter_map(|q: InlineQuery, throttle: Arc<InlineThrottle>| {
+                inline::fetches_weather(&q).then(|| throttle.check(q.from.id.0)).flatten()
+            })
+                .inspect(handler("throttled::inline"))
+                .endpoint(throttled::inline_handler)
+        )
+        .branch(
+            dptree::entry()
+                .inspect(handler("inline"))
+                .endpoint(inline::handler)
+        );
+
     dptree::entry()
+        .chain(logging::update_span())
+        .inspect(|update: Update| metrics::record_active_user(update.from()))
+        .inspect_async(record_activity)
         .branch(dialogue)
         .branch(callback_queries)
+        .branch(inline_queries)
+}
+
+/// Returns a schema step that marks the update as handled by `name`.
+///
+/// Records the handler in the update span and counts the update in metrics.
+fn handler(name: &'static str) -> impl Fn() + Clone + Send + Sync + 'static {
+    move || {
+        logging::record_handler(name);
+        metrics::record_update(name);
+    }
+}
+
+/// Updates the last activity time of the user who sent the update and of the chat it was sent in.
+///
+/// Touching the chat reactivates groups skipped by broadcasts, which are keyed by the chat ID.
+/// Written at most once per [`Activity`] interval per user or chat.
+async fn record_activity(update: Update, db: Repository, activity: Arc<Activity>) {
+    let user_id = update.from().map(|user| user.id.0 as i64);
+    let chat_id = update.chat().map(|chat| chat.id.0).filter(|id| Some(*id) != user_id);
+
+    for id in user_id.into_iter().chain(chat_id) {
+        if !activity.should_touch(id) {
+            continue;
+        }
+
+        if let Err(e) = db.touch(id).await {
+            // Try again with the next update
+            activity.forget(id);
+            tracing::warn!(error = %logging::error_chain(e.as_ref()), "Could not record activity");
+        }
+    }
 }
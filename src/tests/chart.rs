@@ -0,0 +1,41 @@
+use crate::utils::chart::{render_forecast_chart, ChartPoint};
+
+/// PNG file signature.
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// 48 hours of 3-hour slots starting at midnight, crossing zero degrees.
+fn points() -> Vec<ChartPoint> {
+    (0..17)
+        .map(|i| ChartPoint {
+            hour: (i * 3 % 24) as u32,
+            temp: -2.5 + f64::from(i) * 0.8,
+            pop: f64::from(i % 5) / 4.0,
+        })
+        .collect()
+}
+
+#[test]
+fn same_points_render_the_same_image() {
+    let first = render_forecast_chart(&points(), "°C").unwrap();
+    let second = render_forecast_chart(&points(), "°C").unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn chart_is_a_png_of_the_expected_size() {
+    let png = render_forecast_chart(&points(), "°C").unwrap();
+
+    assert!(png.starts_with(PNG_SIGNATURE));
+    // The IHDR chunk follows the signature: length, type, then width and height
+    assert_eq!(&png[12..16], b"IHDR");
+    assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), 960);
+    assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), 480);
+}
+
+#[test]
+fn empty_forecast_still_renders() {
+    let png = render_forecast_chart(&[], "K").unwrap();
+
+    assert!(png.starts_with(PNG_SIGNATURE));
+}
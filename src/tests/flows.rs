@@ -0,0 +1,343 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+
+use crate::api::FixtureProvider;
+use crate::api::models::{AirQuality, WeatherResponse};
+use crate::api::provider::{ProviderError, WeatherProvider};
+use crate::enums::languages::Languages;
+use crate::tests::harness::{Request, TestBot};
+use crate::uv_alerts;
+
+/// Returns the only request made with `method`.
+fn single<'a>(requests: &'a [Request], method: &str) -> &'a Request {
+    let matching = requests.iter().filter(|r| r.method == method).collect::<Vec<_>>();
+    assert_eq!(matching.len(), 1, "expected one {method} in {requests:#?}");
+    matching[0]
+}
+
+/// Registers the chat with a city through `/start`.
+async fn register(bot: &TestBot, chat_id: i64, city: &str) {
+    bot.send_text(chat_id, "/start").await;
+    bot.send_text(chat_id, city).await;
+}
+
+#[tokio::test]
+async fn onboarding_weather_and_units() {
+    let chat = 900_001;
+    let bot = TestBot::new().await;
+
+    let requests = bot.send_text(chat, "/start").await;
+    assert!(single(&requests, "SendMessage").text().contains("введіть назву вашого міста"));
+
+    let requests = bot.send_text(chat, "Kyiv").await;
+    let sent = requests.iter().filter(|r| r.method == "SendMessage").collect::<Vec<_>>();
+    assert_eq!(sent.len(), 2);
+    assert!(sent[0].text().contains("Місто збережено"));
+    assert!(sent[1].text().contains("Kyiv"));
+    assert!(sent[1].buttons().contains(&"today"));
+
+    let requests = bot.press(chat, "today").await;
+    let forecast = single(&requests, "EditMessageText");
+    assert!(forecast.text().contains("Погода в \u{2068}Kyiv\u{2069}"));
+    assert!(forecast.text().contains("\u{2068}C\u{2069}"));
+    assert_eq!(forecast.buttons(), ["start"]);
+    single(&requests, "AnswerCallbackQuery");
+
+    let requests = bot.press(chat, "settings-hub").await;
+    assert!(single(&requests, "EditMessageText").buttons().contains(&"select-units"));
+
+    bot.press(chat, "select-units").await;
+    bot.press(chat, "temperature").await;
+    let requests = bot.press(chat, "fahrenheit").await;
+    assert!(single(&requests, "AnswerCallbackQuery").text().contains("успішно оновлена"));
+
+    let requests = bot.press(chat, "today").await;
+    assert!(single(&requests, "EditMessageText").text().contains("\u{2068}F\u{2069}"));
+}
+
+/// Provider that knows no cities.
+struct UnknownCities;
+
+#[async_trait]
+impl WeatherProvider for UnknownCities {
+    async fn forecast(&self, _city: &str, _lang: Languages) -> Result<WeatherResponse, ProviderError> {
+        Err(ProviderError::CityNotFound)
+    }
+
+    async fn air_quality(&self, _city: &str) -> Result<AirQuality, ProviderError> {
+        Err(ProviderError::CityNotFound)
+    }
+}
+
+#[tokio::test]
+async fn unknown_city_offers_city_change() {
+    let chat = 900_002;
+    let bot = TestBot::with_provider(Arc::new(UnknownCities)).await;
+    register(&bot, chat, "Atlantis").await;
+
+    let requests = bot.press(chat, "today").await;
+    let message = single(&requests, "EditMessageText");
+    assert!(message.text().contains("не знає цього міста"));
+    assert!(message.buttons().contains(&"change-city"));
+
+    let requests = bot.press(chat, "change-city").await;
+    assert!(single(&requests, "EditMessageText").text().contains("назву нового міста"));
+
+    let requests = bot.send_text(chat, "Kyiv").await;
+    assert!(requests.iter().any(|r| r.text().contains("Ваше місто") && r.text().contains("Kyiv")));
+}
+
+#[tokio::test]
+async fn weather_requests_are_throttled() {
+    let chat = 900_003;
+    let bot = TestBot::new().await;
+    register(&bot, chat, "Kyiv").await;
+
+    for _ in 0..5 {
+        let requests = bot.press(chat, "today").await;
+        single(&requests, "EditMessageText");
+    }
+
+    let requests = bot.press(chat, "today").await;
+    assert!(requests.iter().all(|r| r.method != "EditMessageText"));
+    assert!(single(&requests, "AnswerCallbackQuery").text().contains("Забагато запитів"));
+}
+
+/// Fixture provider that counts forecast requests.
+#[derive(Default)]
+struct CountingProvider {
+    forecasts: AtomicUsize,
+}
+
+#[async_trait]
+impl WeatherProvider for CountingProvider {
+    async fn forecast(&self, city: &str, lang: Languages) -> Result<WeatherResponse, ProviderError> {
+        self.forecasts.fetch_add(1, Ordering::Relaxed);
+        FixtureProvider.forecast(city, lang).await
+    }
+
+    async fn air_quality(&self, city: &str) -> Result<AirQuality, ProviderError> {
+        FixtureProvider.air_quality(city).await
+    }
+}
+
+#[tokio::test]
+async fn inline_queries_and_weather_command_are_throttled() {
+    let chat = 900_008;
+    let provider = Arc::new(CountingProvider::default());
+    let bot = TestBot::with_provider(provider.clone()).await;
+    register(&bot, chat, "Kyiv").await;
+
+    // The first keystrokes of a city name are not looked up
+    let requests = bot.inline(chat, "Ky").await;
+    assert_eq!(single(&requests, "AnswerInlineQuery").body["results"], serde_json::json!([]));
+    assert_eq!(provider.forecasts.load(Ordering::Relaxed), 0);
+
+    // Inline queries have their own limit, typing doesn't use up the buttons
+    for _ in 0..8 {
+        let requests = bot.inline(chat, "Kyiv").await;
+        assert_eq!(single(&requests, "AnswerInlineQuery").body["results"].as_array().unwrap().len(), 3);
+    }
+    let requests = bot.inline(chat, "Kyiv").await;
+    let answer = single(&requests, "AnswerInlineQuery");
+    assert_eq!(answer.body["results"], serde_json::json!([]));
+    assert_eq!(answer.body["cache_time"], 0);
+
+    // /weather shares the limit with the buttons
+    for _ in 0..4 {
+        let requests = bot.press(chat, "today").await;
+        single(&requests, "EditMessageText");
+    }
+    let requests = bot.send_text(chat, "/weather").await;
+    assert!(single(&requests, "SendMessage").text().contains("Kyiv"));
+
+    let requests = bot.send_text(chat, "/weather").await;
+    assert!(single(&requests, "SendMessage").text().contains("Забагато запитів"));
+    assert_eq!(provider.forecasts.load(Ordering::Relaxed), 13);
+}
+
+/// Fixture provider with a very high UV index all day.
+struct SunnyProvider;
+
+#[async_trait]
+impl WeatherProvider for SunnyProvider {
+    async fn forecast(&self, city: &str, lang: Languages) -> Result<WeatherResponse, ProviderError> {
+        let mut response = FixtureProvider.forecast(city, lang).await?;
+        for forecast in &mut response.list {
+            forecast.uv_index_max = Some(9.0);
+        }
+        Ok(response)
+    }
+
+    async fn air_quality(&self, city: &str) -> Result<AirQuality, ProviderError> {
+        FixtureProvider.air_quality(city).await
+    }
+}
+
+#[tokio::test]
+async fn uv_alerts_are_sent_once_in_the_morning() {
+    let chat = 900_012;
+    let bot = TestBot::with_provider(Arc::new(SunnyProvider)).await;
+    register(&bot, chat, "Kyiv & region").await;
+    let (users, provider, delivery) = (bot.users(), bot.provider(), bot.delivery());
+    let check = |now| uv_alerts::check(&users, &provider, &delivery, now);
+
+    // The fixture city is at UTC+2
+    let morning = Utc::now().date_naive().and_hms_opt(6, 0, 0).unwrap().and_utc();
+    let evening = morning + TimeDelta::hours(10);
+
+    // Off until the user turns them on
+    assert!(bot.run_job(check(morning)).await.is_empty());
+
+    let requests = bot.press(chat, "uv-alerts").await;
+    assert!(single(&requests, "AnswerCallbackQuery").text().contains("увімкнено"));
+
+    assert!(bot.run_job(check(evening)).await.is_empty());
+
+    let requests = bot.run_job(check(morning)).await;
+    let alert = single(&requests, "SendMessage");
+    assert_eq!(alert.body["chat_id"], chat);
+    assert!(alert.text().contains("Kyiv &amp; region"));
+    assert!(alert.text().contains("УФ-індекс сьогодні"));
+
+    assert!(bot.run_job(check(morning + TimeDelta::minutes(30))).await.is_empty());
+}
+
+#[tokio::test]
+async fn cities_are_escaped_in_html_messages() {
+    let chat = 900_013;
+    let bot = TestBot::new().await;
+    register(&bot, chat, "Kyiv & <region>").await;
+
+    let requests = bot.press(chat, "today").await;
+    assert!(single(&requests, "EditMessageText").text().contains("Kyiv &amp; &lt;region&gt;"));
+
+    let requests = bot.press(chat, "start").await;
+    assert!(requests.iter().any(|r| r.text().contains("Kyiv &amp; &lt;region&gt;")));
+
+    let requests = bot.inline(chat, "lviv & <region>").await;
+    let result = &single(&requests, "AnswerInlineQuery").body["results"][0];
+    assert!(result["input_message_content"]["message_text"].as_str().unwrap().contains("Lviv &amp; &lt;region&gt;"));
+    assert!(result["title"].as_str().unwrap().contains("Lviv & <region>"));
+}
+
+#[tokio::test]
+async fn delete_me_removes_stored_data() {
+    let chat = 900_004;
+    let bot = TestBot::new().await;
+    register(&bot, chat, "Kyiv").await;
+
+    let requests = bot.send_text(chat, "/deleteme").await;
+    let prompt = single(&requests, "SendMessage");
+    assert!(prompt.text().contains("Видалити ваші дані?"));
+    assert_eq!(prompt.buttons(), ["delete-confirm", "delete-cancel"]);
+
+    let requests = bot.press(chat, "delete-cancel").await;
+    assert!(single(&requests, "EditMessageText").text().contains("Видалення скасовано"));
+
+    // Use up the request limit, it is reset together with the data
+    for _ in 0..5 {
+        bot.press(chat, "today").await;
+    }
+
+    let requests = bot.press(chat, "delete-confirm").await;
+    assert!(single(&requests, "EditMessageText").text().contains("Ваші дані видалено"));
+
+    let requests = bot.send_text(chat, "/export").await;
+    assert!(single(&requests, "SendMessage").text().contains("не зберігає жодних даних"));
+
+    let requests = bot.send_text(chat, "/start").await;
+    assert!(single(&requests, "SendMessage").text().contains("введіть назву вашого міста"));
+
+    bot.send_text(chat, "Kyiv").await;
+    let requests = bot.press(chat, "today").await;
+    single(&requests, "EditMessageText");
+}
+
+#[tokio::test]
+async fn messages_in_a_group_reactivate_it() {
+    let group = -900_011;
+    let bot = TestBot::new().await;
+    let users = bot.users();
+    users.upsert_city(group, "Kyiv", Languages::default().as_str()).await.unwrap();
+
+    // Delivery deactivates the group by its chat ID after Telegram refuses a message
+    users.set_active(group, false).await.unwrap();
+    assert!(!users.chat_ids().await.unwrap().contains(&group));
+
+    bot.send_text(group, "/weather").await;
+    assert!(users.chat_ids().await.unwrap().contains(&group));
+}
+
+#[tokio::test]
+async fn export_sends_stored_data() {
+    let chat = 900_005;
+    let bot = TestBot::new().await;
+
+    let requests = bot.send_text(chat, "/export").await;
+    assert!(single(&requests, "SendMessage").text().contains("не зберігає жодних даних"));
+
+    register(&bot, chat, "Kyiv").await;
+    let requests = bot.send_text(chat, "/export").await;
+    single(&requests, "SendDocument");
+}
+
+#[tokio::test]
+async fn onboarding_uses_client_language() {
+    let chat = 900_006;
+    let bot = TestBot::new().await.with_language_code(Some("de-AT"));
+
+    let requests = bot.send_text(chat, "/start").await;
+    assert!(single(&requests, "SendMessage").text().contains("gib den Namen deiner Stadt ein"));
+
+    let requests = bot.send_text(chat, "Wien").await;
+    let sent = requests.iter().filter(|r| r.method == "SendMessage").collect::<Vec<_>>();
+    assert!(sent[0].text().contains("erfolgreich gespeichert"));
+    assert!(sent[1].text().contains("Deine Stadt"));
+
+    // The detected language is stored, later updates don't depend on the client
+    let bot = bot.with_language_code(Some("en"));
+    let requests = bot.send_text(chat, "/start").await;
+    assert!(single(&requests, "SendMessage").text().contains("Deine Stadt"));
+}
+
+#[tokio::test]
+async fn unsupported_languages_use_fallbacks() {
+    let bot = TestBot::new().await.with_language_code(Some("ru"));
+    let requests = bot.send_text(900_007, "/start").await;
+    assert!(single(&requests, "SendMessage").text().contains("enter the name of your city"));
+
+    let bot = bot.with_language_code(Some("it-IT"));
+    let requests = bot.send_text(900_008, "/start").await;
+    assert!(single(&requests, "SendMessage").text().contains("введіть назву вашого міста"));
+
+    let bot = bot.with_language_code(None);
+    let requests = bot.send_text(900_009, "/start").await;
+    assert!(single(&requests, "SendMessage").text().contains("введіть назву вашого міста"));
+}
+
+#[tokio::test]
+async fn language_selection_lists_every_bundle() {
+    let chat = 900_010;
+    let bot = TestBot::new().await;
+    register(&bot, chat, "Kyiv").await;
+
+    let requests = bot.press(chat, "select-language").await;
+    let buttons = single(&requests, "EditMessageText").buttons();
+    for lang in ["de", "en", "es", "fr", "pl", "uk"] {
+        assert!(buttons.contains(&format!("language-{lang}").as_str()), "no {lang} button in {buttons:?}");
+    }
+
+    let requests = bot.press(chat, "language-pl").await;
+    assert!(single(&requests, "AnswerCallbackQuery").text().contains("Język został zmieniony"));
+
+    let requests = bot.press(chat, "start").await;
+    assert!(requests.iter().any(|r| r.text().contains("Twoje miasto")));
+
+    // Keyboards sent before languages were discovered from `locales/` keep working
+    let requests = bot.press(chat, "english").await;
+    assert!(single(&requests, "AnswerCallbackQuery").text().contains("Language updated"));
+}
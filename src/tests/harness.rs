@@ -0,0 +1,369 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::body::Bytes;
+use axum::extract::{Path, State as AxumState};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde_json::{json, Value};
+use teloxide::dispatching::dialogue::InMemStorage;
+use teloxide::dispatching::{DefaultKey, UpdateHandler};
+use teloxide::dptree::di::DependencyMap;
+use teloxide::dptree;
+use teloxide::prelude::*;
+use teloxide::types::Update;
+
+use crate::activity::Activity;
+use crate::api::{FixtureProvider, Provider};
+use crate::config::{Config, ProviderKind};
+use crate::db::{MemoryUserRepository, Repository};
+use crate::delivery::Delivery;
+use crate::enums::languages::Languages;
+use crate::logging::LogFormat;
+use crate::schema::schema;
+use crate::states::State;
+use crate::throttle::{InlineThrottle, Throttle};
+
+/// A Bot API request made by the bot.
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// Method name as sent by teloxide, e.g. `SendMessage`.
+    pub method: String,
+    /// JSON payload, `Null` for multipart requests such as `SendPhoto` and `SendDocument`.
+    pub body: Value,
+}
+
+impl Request {
+    /// Returns the message text, or the alert text of `AnswerCallbackQuery`.
+    pub fn text(&self) -> &str {
+        self.body["text"].as_str().unwrap_or_default()
+    }
+
+    /// Returns the callback data of all inline keyboard buttons.
+    pub fn buttons(&self) -> Vec<&str> {
+        self.body["reply_markup"]["inline_keyboard"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_array)
+            .flatten()
+            .filter_map(|button| button["callback_data"].as_str())
+            .collect()
+    }
+}
+
+/// Local stand-in for the Telegram Bot API that records every request.
+///
+/// Answers `GetMe`, echoes sent and edited messages back as [`Message`]s
+/// and returns `true` for everything else.
+struct FakeTelegram {
+    address: SocketAddr,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+#[derive(Clone)]
+struct FakeState {
+    requests: Arc<Mutex<Vec<Request>>>,
+    next_message_id: Arc<AtomicI32>,
+}
+
+impl FakeTelegram {
+    async fn start() -> Self {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let state = FakeState {
+            requests: requests.clone(),
+            next_message_id: Arc::new(AtomicI32::new(1000)),
+        };
+
+        let router = Router::new()
+            .route("/{token}/{method}", post(api))
+            .with_state(state);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        Self { address, requests }
+    }
+}
+
+async fn api(
+    AxumState(state): AxumState<FakeState>,
+    Path((_token, method)): Path<(String, String)>,
+    body: Bytes,
+) -> Json<Value> {
+    let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+    state.requests.lock().unwrap().push(Request { method: method.clone(), body: body.clone() });
+
+    let result = match method.as_str() {
+        "GetMe" => json!({
+            "id": 1,
+            "is_bot": true,
+            "first_name": "Weather",
+            "username": "weather_test_bot",
+            "can_join_groups": true,
+            "can_read_all_group_messages": false,
+            "supports_inline_queries": true,
+            "has_main_web_app": false,
+        }),
+        "SendMessage" | "EditMessageText" | "SendPhoto" | "SendDocument" => {
+            let message_id = body["message_id"]
+                .as_i64()
+                .unwrap_or_else(|| state.next_message_id.fetch_add(1, Ordering::Relaxed) as i64);
+            let chat_id = body["chat_id"].as_i64().unwrap_or_default();
+
+            json!({
+                "message_id": message_id,
+                "date": 0,
+                "chat": chat_json(chat_id),
+                "text": body["text"].as_str().unwrap_or_default(),
+            })
+        }
+        _ => json!(true),
+    };
+
+    Json(json!({ "ok": true, "result": result }))
+}
+
+/// Returns the Bot API chat object, negative IDs are groups.
+fn chat_json(chat_id: i64) -> Value {
+    if chat_id < 0 {
+        json!({ "id": chat_id, "type": "group", "title": "Test group" })
+    } else {
+        json!({ "id": chat_id, "type": "private", "first_name": "Test" })
+    }
+}
+
+/// The real update [`schema`] wired to a [`FakeTelegram`] server and an in-memory user repository.
+///
+/// Updates are dispatched one at a time, so the requests made for an update
+/// are exactly those recorded while it was handled.
+pub struct TestBot {
+    telegram: FakeTelegram,
+    handler: UpdateHandler<Box<dyn Error + Send + Sync + 'static>>,
+    deps: DependencyMap,
+    next_update_id: AtomicI32,
+    /// `language_code` of the test user's Telegram client.
+    language_code: Option<String>,
+}
+
+impl TestBot {
+    /// Starts a bot with the [`FixtureProvider`] and no stored users.
+    pub async fn new() -> Self {
+        Self::with_provider(Arc::new(FixtureProvider)).await
+    }
+
+    /// Starts a bot with a custom weather provider, see [`TestBot::new`].
+    pub async fn with_provider(provider: Provider) -> Self {
+        let users: Repository = Arc::new(MemoryUserRepository::default());
+
+        let telegram = FakeTelegram::start().await;
+        let api_url = reqwest::Url::parse(&format!("http://{}", telegram.address)).unwrap();
+        let bot = Bot::new("test-token").set_api_url(api_url.clone());
+        let me = bot.get_me().await.expect("fake Bot API is not available");
+
+        let config = Arc::new(test_config(api_url));
+        let delivery = Delivery::new(bot.clone(), users.clone(), config.delivery_rate);
+        let throttle = Arc::new(Throttle::new(config.throttle_limit, config.throttle_window));
+        let inline_throttle = Arc::new(InlineThrottle(Throttle::new(config.inline_throttle_limit, config.throttle_window)));
+
+        let deps = dptree::deps![
+            bot,
+            me,
+            users,
+            InMemStorage::<State>::new(),
+            provider,
+            config,
+            delivery,
+            throttle,
+            inline_throttle,
+            Arc::new(Activity::default())
+        ];
+
+        Self {
+            telegram,
+            handler: schema(),
+            deps,
+            next_update_id: AtomicI32::new(1),
+            language_code: Some("uk".to_string()),
+        }
+    }
+
+    /// Sets the `language_code` sent with the test user, `None` omits it.
+    pub fn with_language_code(mut self, language_code: Option<&str>) -> Self {
+        self.language_code = language_code.map(str::to_string);
+        self
+    }
+
+    /// Sends a text message from the user of a private chat.
+    ///
+    /// Messages starting with `/` are marked as bot commands.
+    ///
+    /// # Returns
+    /// The Bot API requests made while handling the message.
+    pub async fn send_text(&self, chat_id: i64, text: &str) -> Vec<Request> {
+        self.dispatch(self.text_update(chat_id, text)).await
+    }
+
+    /// Returns the update of a text message from the user of a private chat, without `update_id`.
+    ///
+    /// Messages starting with `/` are marked as bot commands.
+    pub fn text_update(&self, chat_id: i64, text: &str) -> Value {
+        let entities = if text.starts_with('/') {
+            let length = text.split_whitespace().next().unwrap_or_default().encode_utf16().count();
+            json!([{ "type": "bot_command", "offset": 0, "length": length }])
+        } else {
+            json!([])
+        };
+
+        json!({
+            "message": {
+                "message_id": 1,
+                "date": 0,
+                "chat": chat_json(chat_id),
+                "from": self.user_json(chat_id),
+                "text": text,
+                "entities": entities,
+            }
+        })
+    }
+
+    /// Builds a dispatcher over the same schema and dependencies,
+    /// for tests of update listeners such as the webhook.
+    pub fn dispatcher(&self) -> Dispatcher<Bot, Box<dyn Error + Send + Sync>, DefaultKey> {
+        Dispatcher::builder(self.bot(), schema())
+            .dependencies(self.deps.clone())
+            .build()
+    }
+
+    /// Returns the bot connected to the fake Bot API.
+    pub fn bot(&self) -> Bot {
+        self.deps.get::<Bot>().as_ref().clone()
+    }
+
+    /// Returns the user repository the bot stores settings in.
+    pub fn users(&self) -> Repository {
+        self.deps.get::<Repository>().as_ref().clone()
+    }
+
+    /// Returns the weather provider the bot was started with.
+    pub fn provider(&self) -> Provider {
+        self.deps.get::<Provider>().as_ref().clone()
+    }
+
+    /// Returns the outbound message queue of the bot.
+    pub fn delivery(&self) -> Delivery {
+        self.deps.get::<Delivery>().as_ref().clone()
+    }
+
+    /// Runs a background job, e.g. the UV alerts, and returns the requests it made.
+    pub async fn run_job(&self, job: impl Future<Output = ()>) -> Vec<Request> {
+        let before = self.telegram.requests.lock().unwrap().len();
+        job.await;
+        self.telegram.requests.lock().unwrap()[before..].to_vec()
+    }
+
+    /// Returns all Bot API requests made so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.telegram.requests.lock().unwrap().clone()
+    }
+
+    /// Presses an inline keyboard button under a bot message in a private chat.
+    ///
+    /// # Returns
+    /// The Bot API requests made while handling the callback query.
+    pub async fn press(&self, chat_id: i64, data: &str) -> Vec<Request> {
+        let update_id = self.next_update_id.load(Ordering::Relaxed);
+        self.dispatch(json!({
+            "callback_query": {
+                "id": format!("callback-{update_id}"),
+                "from": self.user_json(chat_id),
+                "chat_instance": "1",
+                "data": data,
+                "message": {
+                    "message_id": 1,
+                    "date": 0,
+                    "chat": chat_json(chat_id),
+                    "text": "",
+                },
+            }
+        }))
+            .await
+    }
+
+    /// Types an inline query (`@bot <query>`) as the user.
+    ///
+    /// # Returns
+    /// The Bot API requests made while handling the inline query.
+    pub async fn inline(&self, user_id: i64, query: &str) -> Vec<Request> {
+        let update_id = self.next_update_id.load(Ordering::Relaxed);
+        self.dispatch(json!({
+            "inline_query": {
+                "id": format!("inline-{update_id}"),
+                "from": self.user_json(user_id),
+                "query": query,
+                "offset": "",
+            }
+        }))
+            .await
+    }
+
+    /// Returns the Bot API user object of the test user.
+    fn user_json(&self, user_id: i64) -> Value {
+        json!({
+            "id": user_id.unsigned_abs(),
+            "is_bot": false,
+            "first_name": "Test",
+            "language_code": self.language_code,
+        })
+    }
+
+    /// Runs the schema for the update and returns the requests it made.
+    async fn dispatch(&self, mut update: Value) -> Vec<Request> {
+        update["update_id"] = json!(self.next_update_id.fetch_add(1, Ordering::Relaxed));
+        // `UpdateKind` needs borrowed keys, which `from_value` cannot provide
+        let update: Update = serde_json::from_str(&update.to_string()).expect("invalid test update");
+
+        let before = self.telegram.requests.lock().unwrap().len();
+
+        let mut deps = self.deps.clone();
+        deps.insert(update);
+        if let std::ops::ControlFlow::Break(Err(e)) = self.handler.dispatch(deps).await {
+            panic!("handler failed: {e}");
+        }
+
+        self.telegram.requests.lock().unwrap()[before..].to_vec()
+    }
+}
+
+fn test_config(api_url: reqwest::Url) -> Config {
+    Config {
+        telegram_token: "test-token".to_string(),
+        telegram_api_url: Some(api_url),
+        database_url: None,
+        db_pool_size: 2,
+        provider: ProviderKind::Fixture,
+        weather_api_key: None,
+        forecast_cache_ttl: Duration::from_secs(600),
+        air_quality_cache_ttl: Duration::from_secs(1800),
+        provider_connect_timeout: Duration::from_secs(5),
+        provider_timeout: Duration::from_secs(10),
+        provider_retries: 0,
+        admins: Vec::new(),
+        // Differs from the default language, so tests can tell a fallback was used
+        language_fallbacks: HashMap::from([("ru".to_string(), Languages::from_str("en").unwrap())]),
+        webhook: None,
+        health_addr: None,
+        metrics_addr: None,
+        delivery_rate: 30,
+        throttle_limit: 5,
+        inline_throttle_limit: 8,
+        throttle_window: Duration::from_secs(10),
+        log_format: LogFormat::Text,
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeSet;
+use std::fs;
+
+use chrono::NaiveDate;
+use fluent_bundle::{FluentArgs, FluentResource};
+use fluent_syntax::ast::Entry;
+
+use crate::enums::languages::Languages;
+use crate::fluent_args;
+use crate::utils::intl::FluentDateTime;
+use crate::utils::locales::get_text;
+
+/// Returns the message IDs of a bundle.
+fn message_ids(lang: Languages) -> BTreeSet<String> {
+    let resource = FluentResource::try_new(fs::read_to_string(lang.path()).unwrap())
+        .unwrap_or_else(|(_, errors)| panic!("cannot parse {}: {errors:?}", lang.path()));
+
+    resource.entries()
+        .filter_map(|entry| match entry {
+            Entry::Message(message) => Some(message.id.name.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn every_bundle_has_the_same_messages() {
+    let expected = message_ids(Languages::default());
+
+    for lang in Languages::all() {
+        let ids = message_ids(lang);
+        assert_eq!(
+            expected.difference(&ids).collect::<Vec<_>>(),
+            Vec::<&String>::new(),
+            "missing in {}",
+            lang.path(),
+        );
+        assert_eq!(
+            ids.difference(&expected).collect::<Vec<_>>(),
+            Vec::<&String>::new(),
+            "unknown in {}",
+            lang.path(),
+        );
+    }
+}
+
+/// Formats `throttled` without the Unicode isolation marks around placeables.
+fn throttled(lang: &str, seconds: u64) -> String {
+    get_text(Languages::from_str(lang).unwrap(), "throttled", Some(&fluent_args!["seconds" => seconds]))
+        .replace(['\u{2068}', '\u{2069}'], "")
+}
+
+#[test]
+fn plurals_follow_the_language_rules() {
+    assert!(throttled("pl", 1).contains("1 sekundę "));
+    assert!(throttled("pl", 3).contains("3 sekundy "));
+    assert!(throttled("pl", 5).contains("5 sekund "));
+    assert!(throttled("pl", 22).contains("22 sekundy "));
+
+    assert!(throttled("fr", 1).contains("1 seconde "));
+    assert!(throttled("fr", 2).contains("2 secondes "));
+    assert!(throttled("es", 1).contains("1 segundo "));
+}
+
+/// Formats `key` in `lang` without the Unicode isolation marks around placeables.
+fn text(lang: &str, key: &str, args: &FluentArgs) -> String {
+    get_text(Languages::from_str(lang).unwrap(), key, Some(args)).replace(['\u{2068}', '\u{2069}'], "")
+}
+
+#[test]
+fn numbers_are_rounded_and_localized() {
+    let summary = |lang: &str, temp: f64| text(lang, "forecast-summary", &fluent_args![
+        "emoji" => "☀️",
+        "temp" => temp,
+        "temp_unit" => "°C",
+        "description" => "-",
+    ]);
+
+    assert!(summary("en", 21.6).contains(" 22°C"));
+    assert!(summary("en", 21.4).contains(" 21°C"));
+    assert!(summary("en", -0.4).contains(" 0°C"));
+    assert!(summary("en", -2.5).contains(" -3°C"));
+
+    let air_quality = |lang: &str| text(lang, "air-quality", &fluent_args![
+        "city" => "Kyiv",
+        "emoji" => "🟢",
+        "aqi" => 1,
+        "category" => "-",
+        "advice" => "-",
+        "pm2_5" => 12.34,
+        "pm10" => 20.0,
+        "o3" => 1234.56,
+        "no2" => 0.05,
+    ]);
+
+    let en = air_quality("en");
+    assert!(en.contains("12.3 μg/m³") && en.contains("20.0 μg/m³") && en.contains("1,234.6 μg/m³"), "{en}");
+    let de = air_quality("de");
+    assert!(de.contains("12,3 μg/m³") && de.contains("20,0 μg/m³") && de.contains("1.234,6 μg/m³"), "{de}");
+    let uk = air_quality("uk");
+    assert!(uk.contains("1\u{202f}234,6 мкг/м³") && uk.contains("0,1 мкг/м³"), "{uk}");
+}
+
+#[test]
+fn dates_use_the_language_names() {
+    let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let line = |lang: &str| text(lang, "weather-date-line", &fluent_args!["date" => FluentDateTime::from(date)]);
+
+    assert_eq!(line("en"), "📅 Monday 19 October");
+    assert_eq!(line("de"), "📅 Montag 19. Oktober");
+    assert_eq!(line("uk"), "📅 понеділок, 19 жовтня");
+    assert_eq!(line("pl"), "📅 poniedziałek, 19 października");
+    assert_eq!(line("fr"), "📅 lundi 19 octobre");
+}
@@ -0,0 +1,12 @@
+//! End-to-end tests of the update schema.
+//!
+//! The bot talks to a local fake Bot API server, uses the fixture weather provider
+//! and keeps users in memory, so neither network access nor a database is needed.
+//! `webhook` POSTs updates to the webhook listener the same way.
+//! `locales` checks the translation bundles themselves, `chart` the rendered images.
+
+mod chart;
+mod flows;
+mod harness;
+mod locales;
+mod webhook;
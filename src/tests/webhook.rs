@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use serde_json::json;
+
+use crate::tests::harness::TestBot;
+use crate::webhook::{self, WebhookConfig};
+
+/// Header Telegram sends the webhook secret in.
+const SECRET_HEADER: &str = "X-Telegram-Bot-Api-Secret-Token";
+
+/// Returns a local address that is free at the time of the call.
+async fn free_address() -> std::net::SocketAddr {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    listener.local_addr().unwrap()
+}
+
+#[tokio::test]
+async fn webhook_dispatches_only_updates_with_the_secret() {
+    let chat = 900_101;
+    let bot = TestBot::new().await;
+    let address = free_address().await;
+    let config = WebhookConfig {
+        address,
+        url: format!("http://{address}/telegram").parse().unwrap(),
+        secret: "webhook-secret".to_string(),
+        register: false,
+    };
+
+    let dispatcher = bot.dispatcher();
+    let shutdown = dispatcher.shutdown_token();
+
+    let client = async {
+        let http = reqwest::Client::new();
+        let mut update = bot.text_update(chat, "/start");
+        update["update_id"] = json!(1);
+
+        let post = |secret: &'static str| {
+            http.post(config.url.clone())
+                .header(SECRET_HEADER, secret)
+                .json(&update)
+                .send()
+        };
+
+        // The listener starts together with the dispatcher
+        let mut rejected = post("wrong-secret").await;
+        for _ in 0..50 {
+            if rejected.is_ok() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            rejected = post("wrong-secret").await;
+        }
+        assert_eq!(rejected.unwrap().status(), StatusCode::UNAUTHORIZED);
+
+        assert_eq!(post("webhook-secret").await.unwrap().status(), StatusCode::OK);
+
+        // Updates are handled in the background after the request is accepted
+        let mut greetings = 0;
+        for _ in 0..100 {
+            greetings = bot.requests()
+                .iter()
+                .filter(|r| r.method == "SendMessage" && r.body["chat_id"] == chat)
+                .count();
+            if greetings > 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert_eq!(greetings, 1, "only the update with the secret is dispatched");
+
+        shutdown.shutdown().expect("dispatcher is running").await;
+    };
+
+    let (result, ()) = tokio::join!(webhook::run(dispatcher, bot.bot(), &config), client);
+    result.expect("webhook server failed");
+}
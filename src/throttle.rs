@@ -0,0 +1,92 @@
+use std::collections::{HashMap, VecDeque};
+use std::ops::Deref;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Number of checks between removing idle users from memory.
+const CLEANUP_EVERY: u32 = 1000;
+
+/// Per-user sliding window rate limiter.
+///
+/// Allows at most `limit` requests per user within any `window`.
+pub struct Throttle {
+    limit: usize,
+    window: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// Times of the recent allowed requests of every user, oldest first.
+    requests: HashMap<u64, VecDeque<Instant>>,
+    checks: u32,
+}
+
+/// Marker inserted into the dependencies when a request is over the limit.
+#[derive(Debug, Clone, Copy)]
+pub struct Throttled {
+    /// Time until the user may try again.
+    pub retry_in: Duration,
+}
+
+impl Throttle {
+    /// # Arguments
+    /// - `limit` - maximum number of requests per window
+    /// - `window` - length of the sliding window
+    pub fn new(limit: usize, window: Duration) -> Self {
+        Self {
+            limit,
+            window,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Records a request of the user.
+    ///
+    /// # Returns
+    /// - `None` if the request is allowed
+    /// - `Some(Throttled)` if the user is over the limit, the request is not recorded
+    pub fn check(&self, user_id: u64) -> Option<Throttled> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+
+        state.checks += 1;
+        if state.checks >= CLEANUP_EVERY {
+            state.checks = 0;
+            let window = self.window;
+            state.requests.retain(|_, times| times.back().is_some_and(|last| now.duration_since(*last) < window));
+        }
+
+        let times = state.requests.entry(user_id).or_default();
+        while times.front().is_some_and(|first| now.duration_since(*first) >= self.window) {
+            times.pop_front();
+        }
+
+        if times.len() >= self.limit {
+            let retry_in = times.front().map_or(self.window, |first| self.window - now.duration_since(*first));
+            return Some(Throttled { retry_in });
+        }
+
+        times.push_back(now);
+        None
+    }
+
+    /// Forgets the recent requests of the user.
+    pub fn forget(&self, user_id: u64) {
+        self.state.lock().unwrap().requests.remove(&user_id);
+    }
+}
+
+/// Separate [`Throttle`] for inline queries.
+///
+/// Telegram sends an inline query on almost every keystroke, so typing a city name
+/// must not use up the limit of the weather buttons.
+pub struct InlineThrottle(pub Throttle);
+
+impl Deref for InlineThrottle {
+    type Target = Throttle;
+
+    fn deref(&self) -> &Throttle {
+        &self.0
+    }
+}
@@ -17,6 +17,29 @@ pub trait ChatSource {
     /// Returns the user ID if available.
     fn user_id(&self) -> i64;
 
+    /// Returns the IETF language tag of the user's Telegram client, e.g. `de-AT`.
+    fn language_code(&self) -> Option<&str>;
+
+    /// Returns `true` if the update comes from a group or supergroup chat.
+    ///
+    /// Group chat IDs are always negative in Telegram.
+    fn is_group(&self) -> bool {
+        self.chat_id() < 0
+    }
+
+    /// Returns the ID the settings are stored under.
+    ///
+    /// Group chats share one set of settings keyed by the chat ID,
+    /// private chats use the user ID.
+    fn settings_id(&self) -> i64 {
+        if self.is_group() {
+            self.chat_id()
+        }
+        else {
+            self.user_id()
+        }
+    }
+
     /// Allows treating the object as a dynamic type.
     fn is_any(&self) -> &dyn Any;
 }
@@ -31,11 +54,16 @@ impl ChatSource for Message {
     }
 
     fn user_id(&self) -> i64 {
-        self.from()
+        self.from
+            .as_ref()
             .map(|user| user.id.0 as i64)
             .unwrap_or_default()
     }
 
+    fn language_code(&self) -> Option<&str> {
+        self.from.as_ref()?.language_code.as_deref()
+    }
+
     fn is_any(&self) -> &dyn Any {
         self
     }
@@ -59,6 +87,10 @@ impl ChatSource for CallbackQuery {
         self.from.id.0 as i64
     }
 
+    fn language_code(&self) -> Option<&str> {
+        self.from.language_code.as_deref()
+    }
+
     fn is_any(&self) -> &dyn Any {
         self
     }
@@ -0,0 +1,261 @@
+use png::{BitDepth, ColorType, Encoder, EncodingError};
+
+/// A single point of the forecast chart.
+pub struct ChartPoint {
+    /// Local hour of the slot (0-23).
+    pub hour: u32,
+    /// Temperature in the user's units.
+    pub temp: f64,
+    /// Precipitation probability from 0.0 to 1.0.
+    pub pop: f64,
+}
+
+const WIDTH: usize = 960;
+const HEIGHT: usize = 480;
+
+const MARGIN_LEFT: usize = 80;
+const MARGIN_RIGHT: usize = 80;
+const MARGIN_TOP: usize = 50;
+const MARGIN_BOTTOM: usize = 50;
+
+/// Number of horizontal grid intervals.
+const GRID_STEPS: i64 = 4;
+
+/// Scale factor of the built-in 5x7 font.
+const FONT_SCALE: usize = 2;
+
+type Rgb = [u8; 3];
+
+const BACKGROUND: Rgb = [255, 255, 255];
+const GRID: Rgb = [226, 230, 236];
+const DAY_SEPARATOR: Rgb = [160, 168, 180];
+const AXIS: Rgb = [90, 98, 110];
+const TEXT: Rgb = [40, 44, 52];
+const TEMPERATURE: Rgb = [230, 90, 50];
+const PRECIPITATION: Rgb = [120, 170, 235];
+
+/// Renders a PNG chart with the temperature line and precipitation probability bars.
+///
+/// The output depends only on `points` and `unit_symbol`, so the same input
+/// always produces the same image.
+///
+/// # Arguments
+/// - `points` - Forecast slots in chronological order.
+/// - `unit_symbol` - Temperature unit shown in the legend (e.g. `°C`, `K`).
+///
+/// # Returns
+/// - `Ok(Vec<u8>)` with the PNG-encoded image
+/// - `Err(EncodingError)` if the image could not be encoded
+pub fn render_forecast_chart(points: &[ChartPoint], unit_symbol: &str) -> Result<Vec<u8>, EncodingError> {
+    let mut canvas = Canvas::new(WIDTH, HEIGHT, BACKGROUND);
+
+    let left = MARGIN_LEFT as i64;
+    let right = (WIDTH - MARGIN_RIGHT) as i64;
+    let top = MARGIN_TOP as i64;
+    let bottom = (HEIGHT - MARGIN_BOTTOM) as i64;
+
+    // Temperature axis range, expanded so every grid line is a whole degree
+    let (min, max) = points
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(min, max), p| (min.min(p.temp), max.max(p.temp)));
+    let (min, max) = if points.is_empty() { (0.0, 0.0) } else { (min, max) };
+    let t_min = min.floor() as i64 - 1;
+    let span = ((max.ceil() as i64 + 1 - t_min + GRID_STEPS - 1) / GRID_STEPS).max(1) * GRID_STEPS;
+
+    let y_for_temp = |t: f64| bottom - ((t - t_min as f64) / span as f64 * (bottom - top) as f64).round() as i64;
+    let y_for_pop = |p: f64| bottom - (p.clamp(0.0, 1.0) * (bottom - top) as f64).round() as i64;
+
+    // Grid and axis labels
+    for step in 0..=GRID_STEPS {
+        let y = bottom - step * (bottom - top) / GRID_STEPS;
+        canvas.line(left, y, right, y, GRID);
+
+        let temp_label = (t_min + step * span / GRID_STEPS).to_string();
+        canvas.text_right(left - 10, y - 7, &temp_label, TEXT);
+
+        let pop_label = format!("{}%", step * 100 / GRID_STEPS);
+        canvas.text(right + 10, y - 7, &pop_label, TEXT);
+    }
+
+    // Each slot gets an equal column with the point in its center
+    let slot = (right - left) / points.len().max(1) as i64;
+    let x_for = |i: usize| left + slot / 2 + i as i64 * slot;
+    let bar_width = (slot * 3 / 5).max(2);
+
+    // Day separators and hour labels
+    for (i, point) in points.iter().enumerate() {
+        let x = x_for(i);
+        if point.hour < 3 && i > 0 {
+            canvas.line(x - slot / 2, top, x - slot / 2, bottom, DAY_SEPARATOR);
+        }
+        if i % 2 == 0 {
+            canvas.text_center(x, bottom + 12, &format!("{:02}", point.hour), TEXT);
+        }
+    }
+
+    // Precipitation probability bars
+    for (i, point) in points.iter().enumerate() {
+        let x = x_for(i) - bar_width / 2;
+        let y = y_for_pop(point.pop);
+        canvas.fill_rect(x, y, bar_width, bottom - y, PRECIPITATION);
+    }
+
+    // Temperature line
+    for (i, pair) in points.windows(2).enumerate() {
+        canvas.thick_line(x_for(i), y_for_temp(pair[0].temp), x_for(i + 1), y_for_temp(pair[1].temp), TEMPERATURE);
+    }
+    for (i, point) in points.iter().enumerate() {
+        canvas.fill_rect(x_for(i) - 3, y_for_temp(point.temp) - 3, 7, 7, TEMPERATURE);
+    }
+
+    // Axes
+    canvas.line(left, top, left, bottom, AXIS);
+    canvas.line(right, top, right, bottom, AXIS);
+    canvas.line(left, bottom, right, bottom, AXIS);
+
+    // Legend
+    canvas.fill_rect(left, 16, 14, 14, TEMPERATURE);
+    canvas.text(left + 22, 16, unit_symbol, TEXT);
+    canvas.fill_rect(left + 90, 16, 14, 14, PRECIPITATION);
+    canvas.text(left + 112, 16, "%", TEXT);
+
+    canvas.encode()
+}
+
+/// Minimal RGB raster used to draw the chart.
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: background.repeat(width * height),
+        }
+    }
+
+    fn set(&mut self, x: i64, y: i64, color: Rgb) {
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return;
+        }
+        let i = (y as usize * self.width + x as usize) * 3;
+        self.pixels[i..i + 3].copy_from_slice(&color);
+    }
+
+    fn fill_rect(&mut self, x: i64, y: i64, w: i64, h: i64, color: Rgb) {
+        for dy in 0..h {
+            for dx in 0..w {
+                self.set(x + dx, y + dy, color);
+            }
+        }
+    }
+
+    /// Draws a one pixel line using Bresenham's algorithm.
+    fn line(&mut self, x0: i64, y0: i64, x1: i64, y1: i64, color: Rgb) {
+        let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+        let (sx, sy) = ((x1 - x0).signum(), (y1 - y0).signum());
+        let (mut x, mut y, mut err) = (x0, y0, dx + dy);
+
+        loop {
+            self.set(x, y, color);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Draws a three pixel wide line.
+    fn thick_line(&mut self, x0: i64, y0: i64, x1: i64, y1: i64, color: Rgb) {
+        for offset in -1..=1 {
+            self.line(x0, y0 + offset, x1, y1 + offset, color);
+            self.line(x0 + offset, y0, x1 + offset, y1, color);
+        }
+    }
+
+    fn text(&mut self, x: i64, y: i64, text: &str, color: Rgb) {
+        let advance = ((GLYPH_WIDTH + 1) * FONT_SCALE) as i64;
+        for (i, c) in text.chars().enumerate() {
+            let Some(rows) = glyph(c) else { continue };
+            let gx = x + i as i64 * advance;
+
+            for (row, bits) in rows.iter().enumerate() {
+                for col in 0..GLYPH_WIDTH {
+                    if bits & (1 << (GLYPH_WIDTH - 1 - col)) != 0 {
+                        self.fill_rect(
+                            gx + (col * FONT_SCALE) as i64,
+                            y + (row * FONT_SCALE) as i64,
+                            FONT_SCALE as i64,
+                            FONT_SCALE as i64,
+                            color,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn text_width(text: &str) -> i64 {
+        (text.chars().count() * (GLYPH_WIDTH + 1) * FONT_SCALE) as i64
+    }
+
+    fn text_right(&mut self, x: i64, y: i64, text: &str, color: Rgb) {
+        self.text(x - Self::text_width(text), y, text, color);
+    }
+
+    fn text_center(&mut self, x: i64, y: i64, text: &str, color: Rgb) {
+        self.text(x - Self::text_width(text) / 2, y, text, color);
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, EncodingError> {
+        let mut out = Vec::new();
+        {
+            let mut encoder = Encoder::new(&mut out, self.width as u32, self.height as u32);
+            encoder.set_color(ColorType::Rgb);
+            encoder.set_depth(BitDepth::Eight);
+
+            let mut writer = encoder.write_header()?;
+            writer.write_image_data(&self.pixels)?;
+        }
+        Ok(out)
+    }
+}
+
+const GLYPH_WIDTH: usize = 5;
+
+/// Returns the 5x7 bitmap of a character, one byte per row.
+fn glyph(c: char) -> Option<[u8; 7]> {
+    let rows = match c {
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '%' => [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03],
+        '°' => [0x0C, 0x12, 0x12, 0x0C, 0x00, 0x00, 0x00],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        ' ' => [0x00; 7],
+        _ => return None,
+    };
+    Some(rows)
+}
@@ -1,13 +1,38 @@
 use teloxide::prelude::*;
 use teloxide::types::{InlineKeyboardMarkup, MessageId, ParseMode};
 
-use crate::db::pool::DbPool;
-use crate::db::queries::UserQueries;
+use crate::db::Repository;
 use crate::enums::languages::Languages;
+use crate::logging::error_chain;
 use crate::traits::chat::ChatSource;
 use crate::types::HandlerResult;
 use crate::utils::locales::get_text;
 
+/// Checks whether the user may change the chat's settings.
+///
+/// In private chats the user always can. In group chats only the owner
+/// and administrators can; if the membership check fails, editing is denied.
+///
+/// # Arguments
+/// * `bot` - Reference to the [`Bot`] instance.
+/// * `source` - Any type implementing [`ChatSource`] (`Message` or `CallbackQuery`).
+pub async fn can_edit_settings<T>(bot: &Bot, source: &T) -> bool
+where
+    T: ChatSource
+{
+    if !source.is_group() {
+        return true;
+    }
+
+    match bot.get_chat_member(ChatId(source.chat_id()), UserId(source.user_id() as u64)).await {
+        Ok(member) => member.is_privileged(),
+        Err(e) => {
+            tracing::warn!(error = %error_chain(&e), "Could not check chat member status");
+            false
+        }
+    }
+}
+
 /// Sends or edits a message depending on the update source.
 ///
 /// - If `source` is a [`CallbackQuery`], the existing message will be edited.
@@ -75,7 +100,7 @@ where
 /// # Arguments
 /// - `bot` - Reference to the `Bot` instance used for editing message.
 /// - `callback` - The `CallbackQuery` that triggered this handler.
-/// - `db` - Reference to the database connection pool.
+/// - `db` - Reference to the user repository.
 /// - `locale_key` - The localization key used to fetch the translated text for message.
 /// - `keyboard_fn` - A function that returns an `InlineKeyboardMarkup` based on the user's language.
 ///
@@ -101,14 +126,14 @@ where
 pub async fn hub_handler<F>(
     bot: &Bot,
     callback: &CallbackQuery,
-    db: &DbPool,
+    db: &Repository,
     locale_key: &str,
     keyboard_fn: F,
 ) -> HandlerResult
 where
     F: Fn(Languages) -> InlineKeyboardMarkup,
 {
-    let user = UserQueries::get_user(&db, callback.user_id()).await;
+    let user = db.get_user(callback.settings_id()).await;
 
     if let Some(message) = &callback.message {
         let chat_id = message.chat().id;
@@ -0,0 +1,340 @@
+use std::borrow::Cow;
+
+use chrono::{Datelike, Locale, NaiveDateTime};
+use fluent_bundle::types::{FluentNumber, FluentNumberOptions, FluentType};
+use fluent_bundle::{FluentArgs, FluentValue};
+use intl_memoizer::{IntlLangMemoizer, Memoizable};
+use pure_rust_locales::locale_match;
+use unic_langid::LanguageIdentifier;
+
+use crate::enums::languages::Languages;
+
+// `strftime` directives of a day, a month name and a weekday name in locale patterns
+const DAY: &[&str] = &["%-d", "%d", "%e"];
+const MONTH: &[&str] = &["%B", "%b", "%h"];
+const WEEKDAY: &[&str] = &["%A", "%a"];
+
+/// Number and date conventions of a language, taken from its POSIX locale.
+///
+/// Constructed by the bundle's memoizer, which is keyed by [`Languages::lang_id`].
+struct LocaleData {
+    locale: Locale,
+    decimal_point: &'static str,
+    thousands_sep: &'static str,
+    /// Digits per group, `0` disables grouping.
+    grouping: usize,
+    /// Day and month name, e.g. `%-d %B` or `%-d. %B`.
+    day_month: String,
+    /// Text between the weekday and the date, e.g. `, `.
+    weekday_sep: String,
+    /// Numeric date, e.g. `%d.%m.%Y`.
+    short_date: &'static str,
+    twelve_hour: bool,
+    /// Month names used without a day, where the language declines them after a number.
+    standalone_months: &'static [&'static str],
+}
+
+impl Memoizable for LocaleData {
+    type Args = ();
+    type Error = ();
+
+    fn construct(lang: LanguageIdentifier, _: ()) -> Result<Self, ()> {
+        let locale = Languages::from_str(&lang.to_string()).ok_or(())?.locale();
+
+        let long_date = locale_match!(locale => LC_TIME::DATE_FMT)
+            .unwrap_or(locale_match!(locale => LC_TIME::D_T_FMT));
+        let time = locale_match!(locale => LC_TIME::T_FMT);
+
+        Ok(Self {
+            locale,
+            decimal_point: locale_match!(locale => LC_NUMERIC::DECIMAL_POINT),
+            thousands_sep: locale_match!(locale => LC_NUMERIC::THOUSANDS_SEP),
+            grouping: locale_match!(locale => LC_NUMERIC::GROUPING)
+                .first()
+                .map_or(0, |&g| g.max(0) as usize),
+            day_month: day_month_pattern(long_date).unwrap_or_else(|| "%-d %B".to_string()),
+            weekday_sep: weekday_separator(long_date).unwrap_or_else(|| ", ".to_string()),
+            short_date: locale_match!(locale => LC_TIME::D_FMT),
+            twelve_hour: ["%I", "%r", "%p"].iter().any(|t| time.contains(t)),
+            standalone_months: locale_match!(locale => LC_TIME::ALT_MON)
+                .unwrap_or(locale_match!(locale => LC_TIME::MON)),
+        })
+    }
+}
+
+/// Runs `format` with the conventions of the bundle's language.
+///
+/// Falls back to the default language if the memoizer is keyed by an unknown language.
+fn with_locale<R>(intls: &IntlLangMemoizer, format: impl Fn(&LocaleData) -> R) -> R {
+    match intls.with_try_get::<LocaleData, _, _>((), &format) {
+        Ok(result) => result,
+        Err(()) => format(&LocaleData::construct(Languages::default().lang_id(), ()).expect("default language")),
+    }
+}
+
+/// Returns the first `%` directive in `pattern` starting at `from`, with its position.
+fn find_directive<'a>(pattern: &'a str, from: usize, directives: &[&'a str]) -> Option<(usize, &'a str)> {
+    directives.iter()
+        .filter_map(|d| pattern[from..].find(d).map(|i| (from + i, *d)))
+        .min_by_key(|(i, _)| *i)
+}
+
+/// Extracts how a locale writes a day with a month name, e.g. `%-d. %B` from `%a %-d. %b %H:%M:%S`.
+fn day_month_pattern(long_date: &str) -> Option<String> {
+    let (day_at, day) = find_directive(long_date, 0, DAY)?;
+    let (month_at, _) = find_directive(long_date, day_at, MONTH)?;
+    let between = &long_date[day_at + day.len()..month_at];
+
+    // Only separators may stand between them, otherwise the pattern is not a plain date
+    if between.contains('%') {
+        return None;
+    }
+    Some(format!("%-d{between}%B"))
+}
+
+/// Extracts the separator after the weekday, e.g. `, ` from `%A, %-d %B %Y`.
+fn weekday_separator(long_date: &str) -> Option<String> {
+    let (weekday_at, weekday) = find_directive(long_date, 0, WEEKDAY)?;
+    let rest = &long_date[weekday_at + weekday.len()..];
+    Some(rest[..rest.find('%')?].to_string())
+}
+
+/// Formats a number with the decimal point and digit grouping of the locale.
+///
+/// Supports the `minimumIntegerDigits`, `minimumFractionDigits`, `maximumFractionDigits`
+/// and `useGrouping` options. Halves are rounded away from zero.
+fn format_number(number: &FluentNumber, data: &LocaleData) -> String {
+    let options = &number.options;
+    let min_fraction = options.minimum_fraction_digits.unwrap_or(0);
+    let max_fraction = options.maximum_fraction_digits.unwrap_or(min_fraction.max(3)).max(min_fraction);
+
+    let scale = 10f64.powi(max_fraction as i32);
+    let rounded = (number.value * scale).round() / scale;
+    let digits = format!("{:.max_fraction$}", rounded.abs());
+    let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+
+    let mut fraction = fraction.trim_end_matches('0').to_string();
+    while fraction.len() < min_fraction {
+        fraction.push('0');
+    }
+
+    let integer = format!("{integer:0>width$}", width = options.minimum_integer_digits.unwrap_or(1));
+    let integer = if options.use_grouping && data.grouping > 0 && !data.thousands_sep.is_empty() {
+        group_digits(&integer, data.grouping, data.thousands_sep)
+    } else {
+        integer
+    };
+
+    // Avoids "-0" for small negative values rounded to zero
+    let sign = if rounded < 0.0 { "-" } else { "" };
+    if fraction.is_empty() {
+        format!("{sign}{integer}")
+    } else {
+        format!("{sign}{integer}{}{fraction}", data.decimal_point)
+    }
+}
+
+/// Inserts `separator` between groups of `size` digits, counting from the right.
+fn group_digits(integer: &str, size: usize, separator: &str) -> String {
+    let mut grouped = String::new();
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i).is_multiple_of(size) {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Bundle formatter, writes numbers with the conventions of the bundle's language.
+///
+/// Other values keep the default formatting.
+pub fn format_value(value: &FluentValue, intls: &IntlLangMemoizer) -> Option<String> {
+    match value {
+        FluentValue::Number(number) => Some(with_locale(intls, |data| format_number(number, data))),
+        _ => None,
+    }
+}
+
+/// Fluent `NUMBER` function: `{ NUMBER($temp, maximumFractionDigits: 0) }`.
+///
+/// Returns the number with the given options, so selectors still pick plural
+/// variants by it and the bundle formatter writes it for the language.
+pub fn number<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a> {
+    match positional.first() {
+        Some(FluentValue::Number(number)) => {
+            let mut options = number.options.clone();
+            options.merge(named);
+            FluentValue::Number(FluentNumber::new(number.value, options))
+        }
+        Some(FluentValue::String(s)) => match s.parse::<f64>() {
+            Ok(value) => {
+                let mut options = FluentNumberOptions::default();
+                options.merge(named);
+                FluentValue::Number(FluentNumber::new(value, options))
+            }
+            Err(_) => FluentValue::Error,
+        },
+        _ => FluentValue::Error,
+    }
+}
+
+/// Width of a date or time component, a subset of `Intl.DateTimeFormat` options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Width {
+    Numeric,
+    TwoDigit,
+    Long,
+    Short,
+}
+
+impl Width {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "numeric" => Some(Width::Numeric),
+            "2-digit" => Some(Width::TwoDigit),
+            "long" => Some(Width::Long),
+            "short" | "narrow" => Some(Width::Short),
+            &_ => None,
+        }
+    }
+}
+
+/// Components shown by `DATETIME`, all `None` shows the numeric date.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct DateTimeOptions {
+    weekday: Option<Width>,
+    day: Option<Width>,
+    month: Option<Width>,
+    year: Option<Width>,
+    hour: Option<Width>,
+    minute: Option<Width>,
+}
+
+impl DateTimeOptions {
+    fn merge(&mut self, opts: &FluentArgs) {
+        for (key, value) in opts.iter() {
+            let FluentValue::String(value) = value else {
+                continue;
+            };
+            let width = Width::from_str(value);
+
+            match key {
+                "weekday" => self.weekday = width,
+                "day" => self.day = width,
+                "month" => self.month = width,
+                "year" => self.year = width,
+                "hour" => self.hour = width,
+                "minute" => self.minute = width,
+                &_ => {}
+            }
+        }
+    }
+
+    /// Builds the chrono format string for the options in the locale.
+    fn pattern(&self, data: &LocaleData, month: u32) -> String {
+        let mut date = match (self.day, self.month) {
+            (Some(_), Some(Width::Long)) => data.day_month.clone(),
+            (Some(_), Some(Width::Short)) => data.day_month.replace("%B", "%b"),
+            // Without a day the month name is not declined
+            (None, Some(Width::Long | Width::Short)) => data.standalone_months[month as usize - 1].to_string(),
+            (None, None) if self.weekday.is_some() || self.hour.is_some() => String::new(),
+            _ => data.short_date.to_string(),
+        };
+
+        if self.day == Some(Width::TwoDigit) {
+            date = date.replace("%-d", "%d");
+        }
+        if matches!(self.month, Some(Width::Long | Width::Short)) {
+            match self.year {
+                Some(Width::TwoDigit) => date.push_str(" %y"),
+                Some(_) => date.push_str(" %Y"),
+                None => {}
+            }
+        }
+
+        let mut pattern = match self.weekday {
+            Some(Width::Long) if !date.is_empty() => format!("%A{}{date}", data.weekday_sep),
+            Some(_) if !date.is_empty() => format!("%a{}{date}", data.weekday_sep),
+            Some(Width::Long) => "%A".to_string(),
+            Some(_) => "%a".to_string(),
+            None => date,
+        };
+
+        if self.hour.is_some() || self.minute.is_some() {
+            let time = match (data.twelve_hour, self.hour) {
+                (true, Some(Width::TwoDigit)) => "%I:%M %p",
+                (true, _) => "%-I:%M %p",
+                (false, Some(Width::Numeric)) => "%-H:%M",
+                (false, _) => "%H:%M",
+            };
+            if !pattern.is_empty() {
+                pattern.push(' ');
+            }
+            pattern.push_str(time);
+        }
+
+        pattern
+    }
+}
+
+/// Date and time argument of a message, formatted with `DATETIME`.
+///
+/// The value is shown as is, convert it to the city's local time before.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FluentDateTime {
+    value: NaiveDateTime,
+    options: DateTimeOptions,
+}
+
+impl FluentDateTime {
+    fn format(&self, data: &LocaleData) -> String {
+        let pattern = self.options.pattern(data, self.value.month());
+        self.value.and_utc().format_localized(&pattern, data.locale).to_string()
+    }
+}
+
+impl From<NaiveDateTime> for FluentDateTime {
+    fn from(value: NaiveDateTime) -> Self {
+        Self { value, options: DateTimeOptions::default() }
+    }
+}
+
+impl From<FluentDateTime> for FluentValue<'_> {
+    fn from(value: FluentDateTime) -> Self {
+        FluentValue::Custom(Box::new(value))
+    }
+}
+
+impl FluentType for FluentDateTime {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, intls: &IntlLangMemoizer) -> Cow<'static, str> {
+        with_locale(intls, |data| self.format(data)).into()
+    }
+
+    fn as_string_threadsafe(&self, intls: &intl_memoizer::concurrent::IntlLangMemoizer) -> Cow<'static, str> {
+        intls.with_try_get::<LocaleData, _, _>((), |data| self.format(data))
+            .unwrap_or_else(|()| self.value.to_string())
+            .into()
+    }
+}
+
+/// Fluent `DATETIME` function: `{ DATETIME($date, weekday: "long", day: "numeric", month: "long") }`.
+///
+/// Supports the `weekday`, `day`, `month`, `year`, `hour` and `minute` options,
+/// names of weekdays and months are taken from the bundle's language.
+pub fn datetime<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a> {
+    let Some(FluentValue::Custom(value)) = positional.first() else {
+        return FluentValue::Error;
+    };
+    let Some(value) = value.as_any().downcast_ref::<FluentDateTime>() else {
+        return FluentValue::Error;
+    };
+
+    let mut value = value.clone();
+    value.options.merge(named);
+    value.into()
+}
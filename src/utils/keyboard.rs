@@ -4,7 +4,7 @@ use crate::enums::Callbacks;
 use crate::enums::languages::Languages;
 use crate::utils::locales::get_text;
 
-/// Returns the main hub keyboard with options for today's and tomorrow's weather.
+/// Returns the main hub keyboard with options for today's and tomorrow's weather, sun times, air quality and chart.
 ///
 /// # Arguments
 /// - `lang` - мова кнопок клавіатури
@@ -15,6 +15,11 @@ pub fn get_hub_keyboard(lang: Languages) -> InlineKeyboardMarkup {
     InlineKeyboardMarkup::new(vec![
         vec![InlineKeyboardButton::callback(get_text(lang, "today", None), Callbacks::Today.as_str())],
         vec![InlineKeyboardButton::callback(get_text(lang, "tomorrow", None), Callbacks::Tomorrow.as_str())],
+        vec![
+            InlineKeyboardButton::callback(get_text(lang, "sun-button", None), Callbacks::Sun.as_str()),
+            InlineKeyboardButton::callback(get_text(lang, "air-quality-button", None), Callbacks::AirQuality.as_str()),
+        ],
+        vec![InlineKeyboardButton::callback(get_text(lang, "chart-button", None), Callbacks::Chart.as_str())],
         vec![InlineKeyboardButton::callback(get_text(lang, "settings", None), Callbacks::SettingsHub.as_str())],
     ])
 }
@@ -31,27 +36,43 @@ pub fn get_settings_hub(lang: Languages) -> InlineKeyboardMarkup {
     InlineKeyboardMarkup::new(vec![
         vec![InlineKeyboardButton::callback(get_text(lang, "select-language", None), Callbacks::SelectLanguage.as_str())],
         vec![InlineKeyboardButton::callback(get_text(lang, "select-units", None), Callbacks::SelectUnits.as_str())],
+        vec![InlineKeyboardButton::callback(get_text(lang, "change-city-button", None), Callbacks::ChangeCity.as_str())],
+        vec![InlineKeyboardButton::callback(get_text(lang, "uv-alerts-button", None), Callbacks::UvAlerts.as_str())],
+        vec![InlineKeyboardButton::callback(get_text(lang, "back", None), Callbacks::Start.as_str())],
+    ])
+}
+
+/// Returns the keyboard shown when the saved city is not known to the weather provider.
+pub fn get_city_not_found_keyboard(lang: Languages) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![
+        vec![InlineKeyboardButton::callback(get_text(lang, "change-city-button", None), Callbacks::ChangeCity.as_str())],
         vec![InlineKeyboardButton::callback(get_text(lang, "back", None), Callbacks::Start.as_str())],
     ])
 }
 
+/// Returns the keyboard that confirms or cancels deleting the user's data.
+pub fn get_delete_confirm_keyboard(lang: Languages) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![
+        vec![InlineKeyboardButton::callback(get_text(lang, "delete-confirm-button", None), Callbacks::DeleteConfirm.as_str())],
+        vec![InlineKeyboardButton::callback(get_text(lang, "delete-cancel-button", None), Callbacks::DeleteCancel.as_str())],
+    ])
+}
+
 /// Returns keyboard with languages
 pub fn get_languages_keyboard(lang: Languages) -> InlineKeyboardMarkup {
     let language_buttons = Languages::all()
-        .iter()
-        .map(|l| {
-            InlineKeyboardButton::callback(l.label(), l.callback().as_str())
-        })
-        .collect();
+        .map(|l| InlineKeyboardButton::callback(l.label(), l.callback_data()))
+        .collect::<Vec<_>>();
 
     let back_button = vec![
         InlineKeyboardButton::callback(get_text(lang, "back", None), Callbacks::SettingsHub.as_str())
     ];
 
-    InlineKeyboardMarkup::new(vec![
-        language_buttons,
-        back_button
-    ])
+    // Two languages per row keeps the labels readable on narrow screens
+    let mut rows = language_buttons.chunks(2).map(<[_]>::to_vec).collect::<Vec<_>>();
+    rows.push(back_button);
+
+    InlineKeyboardMarkup::new(rows)
 }
 
 /// Returns keyboard for units settings hub
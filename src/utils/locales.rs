@@ -2,6 +2,7 @@ use std::fs;
 use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
 
 use crate::enums::languages::Languages;
+use crate::utils::intl;
 
 /// Retrieves a localizes message string for a given language and key.
 ///
@@ -10,6 +11,7 @@ use crate::enums::languages::Languages;
 /// - `lang` - The target `Languages` enum value specifying the language.
 /// - `key` - The key of the message to retrieve from the FTL file.
 /// - `args` - Optional `FluentArgs` for dynamic placeholders in the message.
+///   Messages may format them with the `NUMBER` and `DATETIME` functions.
 ///
 /// # Returns
 /// A formatted `String` containing the localizes message.
@@ -24,6 +26,11 @@ pub fn get_text(lang: Languages, key: &str, args: Option<&FluentArgs>) -> String
     let mut bundle = FluentBundle::new(vec![lang_id]);
     bundle.add_resource(resource).unwrap();
 
+    // Numbers and dates are written with the conventions of `lang`, e.g. a decimal comma in German
+    bundle.set_formatter(Some(intl::format_value));
+    bundle.add_function("NUMBER", intl::number).unwrap();
+    bundle.add_function("DATETIME", intl::datetime).unwrap();
+
     let msg = bundle.get_message(key)
         .unwrap_or_else(|| panic!("{} not found", lang.path()));
     let pattern = msg.value().expect("Message has no value");
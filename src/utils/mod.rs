@@ -2,3 +2,5 @@ pub mod keyboard;
 pub mod string;
 pub mod chat;
 pub mod locales;
+pub mod intl;
+pub mod chart;
@@ -0,0 +1,97 @@
+use std::ops::Range;
+use std::time::Duration;
+
+use chrono::{DateTime, Timelike, Utc};
+use fluent_bundle::FluentArgs;
+use teloxide::types::ChatId;
+use teloxide::utils::html;
+
+use crate::api::{today_weather, Provider};
+use crate::db::{Repository, User};
+use crate::delivery::Delivery;
+use crate::enums::languages::Languages;
+use crate::enums::uv::UvRisk;
+use crate::fluent_args;
+use crate::logging::error_chain;
+use crate::utils::locales::get_text;
+
+/// How often chats with UV alerts are checked.
+const CHECK_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Local hours in the chat's city during which the alert is sent, so it arrives in the morning.
+const SEND_HOURS: Range<u32> = 7..11;
+
+/// Sends the daily high UV index alerts until the process exits.
+///
+/// Every [`CHECK_INTERVAL`] each chat that turned on UV alerts gets today's forecast for its city.
+/// If it is the morning there and the peak UV index is high or above, the alert is sent
+/// through the delivery queue, at most once per local day.
+pub async fn run(db: Repository, provider: Provider, delivery: Delivery) {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        check(&db, &provider, &delivery, Utc::now()).await;
+    }
+}
+
+/// Sends the alerts due at `now`.
+pub async fn check(db: &Repository, provider: &Provider, delivery: &Delivery, now: DateTime<Utc>) {
+    let chats = match db.uv_alert_chats().await {
+        Ok(chats) => chats,
+        Err(e) => {
+            tracing::error!(error = %error_chain(e.as_ref()), "Could not load chats for UV alerts");
+            return;
+        }
+    };
+
+    for chat in chats {
+        if let Err(e) = check_chat(db, provider, delivery, &chat, now).await {
+            tracing::warn!(chat_id = chat.id, error = %error_chain(e.as_ref()), "Could not send UV alert");
+        }
+    }
+}
+
+/// Sends the alert to the chat if it is due.
+async fn check_chat(
+    db: &Repository,
+    provider: &Provider,
+    delivery: &Delivery,
+    chat: &User,
+    now: DateTime<Utc>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let lang = Languages::from_str(&chat.language).unwrap_or_default();
+    let response = provider.forecast(&chat.city, lang).await?;
+
+    let local = now.naive_utc() + chrono::Duration::seconds(response.city.timezone as i64);
+    if !SEND_HOURS.contains(&local.hour()) || chat.uv_alert_sent_on.is_some_and(|sent| sent >= local.date()) {
+        return Ok(());
+    }
+
+    let Some(uv) = today_weather(&response)
+        .and_then(|f| f.uv_index_max.or(f.uv_index))
+        .filter(|uv| UvRisk::from_index(*uv).is_alert())
+    else {
+        return Ok(());
+    };
+
+    // Another instance of the bot may be sending the same alert
+    if !db.claim_uv_alert(chat.id, local.date()).await? {
+        return Ok(());
+    }
+
+    delivery.send_message(ChatId(chat.id), &format_uv_alert(&chat.city, uv, lang)).await?;
+    Ok(())
+}
+
+/// Formats the alert sent when the UV index is high
+fn format_uv_alert(city: &str, uv: f64, lang: Languages) -> String {
+    let risk = UvRisk::from_index(uv);
+    let args = fluent_args![
+        "city" => html::escape(city),
+        "uv" => uv.round() as i64,
+        "risk" => get_text(lang, risk.locale_key(), None),
+        "advice" => get_text(lang, risk.advice_key(), None),
+    ];
+
+    get_text(lang, "uv-alert", Some(&args))
+}
@@ -0,0 +1,82 @@
+use std::error::Error;
+use std::fmt;
+use std::net::SocketAddr;
+
+use reqwest::Url;
+use teloxide::dispatching::DefaultKey;
+use teloxide::prelude::*;
+use teloxide::update_listeners::webhooks::{self, Options};
+
+use crate::config::HIDDEN;
+
+/// Settings for running the bot in webhook mode.
+///
+/// Loaded as part of [`Config`](crate::config::Config).
+pub struct WebhookConfig {
+    /// Local address the HTTP listener binds to.
+    pub address: SocketAddr,
+    /// Public URL Telegram sends updates to. Its path is also the local route.
+    pub url: Url,
+    /// Secret expected in the `X-Telegram-Bot-Api-Secret-Token` header.
+    pub secret: String,
+    /// Whether to register the webhook with Telegram on start and remove it on shutdown.
+    ///
+    /// Disable to test locally by POSTing fake updates to the listener.
+    pub register: bool,
+}
+
+impl fmt::Debug for WebhookConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookConfig")
+            .field("address", &self.address)
+            .field("url", &self.url)
+            .field("secret", &HIDDEN)
+            .field("register", &self.register)
+            .finish()
+    }
+}
+
+/// Runs the dispatcher with updates received over a webhook.
+///
+/// 1. Binds the local address, then registers the webhook with Telegram (if enabled),
+///    so a failed bind never leaves a registered webhook with nothing listening.
+/// 2. Serves the webhook route on the local address. Requests without the
+///    correct secret token header are rejected with `401 Unauthorized`.
+/// 3. Dispatches updates until shutdown (e.g. Ctrl+C), then stops the HTTP
+///    server gracefully and removes the webhook (if registered).
+pub async fn run(
+    mut dispatcher: Dispatcher<Bot, Box<dyn Error + Send + Sync>, DefaultKey>,
+    bot: Bot,
+    config: &WebhookConfig,
+) -> Result<(), Box<dyn Error>> {
+    let options = Options::new(config.address, config.url.clone())
+        .secret_token(config.secret.clone());
+    let (listener, stop_flag, router) = webhooks::axum_no_setup(options);
+
+    let tcp_listener = tokio::net::TcpListener::bind(config.address).await?;
+
+    if config.register {
+        bot.set_webhook(config.url.clone())
+            .secret_token(config.secret.clone())
+            .await?;
+    }
+
+    let server = tokio::spawn(async move {
+        axum::serve(tcp_listener, router)
+            .with_graceful_shutdown(stop_flag)
+            .await
+    });
+
+    dispatcher
+        .dispatch_with_listener(listener, LoggingErrorHandler::with_custom_text("Webhook listener error"))
+        .await;
+
+    // Remove the webhook even if the server failed, nothing listens anymore
+    let served = server.await;
+    if config.register {
+        bot.delete_webhook().await?;
+    }
+    served??;
+
+    Ok(())
+}